@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Account as Token2022Account;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use fuse::constants::SEED;
+use fuse::state::BondingCurve;
+
+// The hook lives outside the fuse program: the runtime rejects
+// fuse → Token-2022 → fuse reentrancy during buy/sell transfers
+declare_id!("9vTiDqjpS3wqi4F9XR2ZaXF2xu1Tztx4FF8fzm2Fp4tE");
+
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas"; // Fixed by the transfer hook interface
+
+#[program]
+pub mod fuse_hook {
+    use super::*;
+
+    /// Create the extra account list Token-2022 needs to call `transfer_hook`
+    ///
+    /// Required once per curve-only mint before any transfer
+    pub fn initialize_extra_account_meta_list(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_account_metas()?)?;
+
+        msg!("Transfer hook ready: {}", ctx.accounts.mint.key());
+
+        Ok(())
+    }
+
+    /// Enforce curve-only mode (invoked by Token-2022 on every transfer)
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+        // Only Token-2022 may call the hook, and only mid-transfer
+        let source_info = ctx.accounts.source_token.to_account_info();
+        let source_data = source_info.try_borrow_data()?;
+        let source_account = StateWithExtensions::<Token2022Account>::unpack(&source_data)?;
+        let hook_account = source_account.get_extension::<TransferHookAccount>()?;
+        require!(bool::from(hook_account.transferring), HookError::NotTransferring);
+
+        let curve_config = &ctx.accounts.curve_config;
+        if !curve_config.curve_only_transfers || curve_config.complete {
+            return Ok(());
+        }
+
+        // Until graduation a transfer must be signed by the curve PDA (buy,
+        // claims, migrate) or land in a curve-owned vault (sell)
+        let curve_key = curve_config.key();
+        require!(
+            ctx.accounts.owner.key() == curve_key || ctx.accounts.destination_token.owner == curve_key,
+            HookError::TransferRestricted
+        );
+
+        Ok(())
+    }
+}
+
+/// Extra accounts resolved by Token-2022 for every transfer of the mint
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        // Index 5: the fuse program
+        ExtraAccountMeta::new_with_pubkey(&fuse::ID, false, false)?,
+        // Index 6: bonding curve PDA [SEED, mint] owned by the fuse program
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            5,
+            &[
+                Seed::Literal { bytes: SEED.to_vec() },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            false,
+        )?,
+    ])
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: ExtraAccountMetaList PDA, initialized and written by the handler
+    #[account(
+        init,
+        payer = payer,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The mint must belong to a fuse bonding curve
+    #[account(
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        seeds::program = fuse::ID,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Source token account owner or delegate, validated by Token-2022
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList PDA
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: fuse program, only used to derive the curve PDA
+    #[account(address = fuse::ID)]
+    pub fuse_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        seeds::program = fuse::ID,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+}
+
+#[error_code]
+pub enum HookError {
+    #[msg("This token can only be traded through the curve until it graduates.")]
+    TransferRestricted,
+
+    #[msg("The transfer hook can only be called during a transfer.")]
+    NotTransferring,
+}
@@ -4,6 +4,17 @@ use anchor_lang::prelude::*;
 pub const SEED: &[u8] = b"curve";
 pub const USER_SEED: &[u8] = b"user";
 pub const REFERRAL_SEED: &[u8] = b"referral";
+pub const REFERRAL_VAULT_SEED: &[u8] = b"referral_vault";
+pub const AUCTION_SEED: &[u8] = b"auction";
+pub const AUCTION_VAULT_SEED: &[u8] = b"auction_vault";
+pub const BID_SEED: &[u8] = b"bid";
+pub const CREATOR_LOCK_SEED: &[u8] = b"creator_lock";
+pub const FEE_SPLIT_SEED: &[u8] = b"fee_split";
+pub const POOL_FEES_SEED: &[u8] = b"pool_fees";
+pub const REFERRAL_CONFIG_SEED: &[u8] = b"referral_config";
+pub const REFERRAL_BOOST_SEED: &[u8] = b"referral_boost";
+pub const CLOSED_PROFILE_SEED: &[u8] = b"closed_profile";
+pub const METADATA_SEED: &[u8] = b"metadata"; // Metaplex metadata PDA prefix
 
 pub const VIRTUAL_SOL_RESERVES: u64 = 30_000_000_000; // 30 SOL
 pub const VIRTUAL_TOKEN_RESERVES: u64 = 1_073_000_000_000_000; // 1.073B tokens (6 decimals)
@@ -23,12 +34,64 @@ pub const GRADUATION_SOL_THRESHOLD: u64 = 85_000_000_000; // ~85 SOL in real res
 pub const FEE_BASIS_POINTS: u64 = 100; // 1% total fee
 pub const PROTOCOL_FEE_SHARE: u64 = 80; // 80% of fee goes to protocol
 pub const CREATOR_FEE_SHARE: u64 = 20; // 20% of fee goes to creator
+pub const REFERRAL_FEE_SHARE: u64 = 10; // 10% of protocol fee goes to the direct referrer (base tier)
+pub const SECOND_TIER_REFERRAL_FEE_SHARE: u64 = 3; // 3% of protocol fee goes to the referrer's referrer
+pub const MAX_FEE_RECIPIENTS: usize = 5; // Max wallets sharing the creator fee
+pub const TREASURY: Pubkey = pubkey!("4j1591eHGUZvRQgAGKSW2sriMQkDinSDRnA7oXdCHyT1"); // Protocol fee wallet
+
+// =====================
+// REFERRAL TIERS
+// =====================
+pub const MAX_REFERRAL_TIERS: usize = 5;
+pub const MAX_REFERRAL_FEE_SHARE: u64 = 50; // No tier may pay out more than 50% of the protocol fee
+pub const MAX_REFERRAL_BINDING_DURATION: i64 = 63_072_000; // Referrer bindings last at most 2 years (0 = forever)
+
+// =====================
+// REFERRAL BOOST
+// =====================
+pub const MAX_REFERRAL_BOOST_BPS: u16 = 25; // Creator bonus to referrers, at most 0.25% of trade volume (well under the 1% fee)
+
+// =====================
+// USERNAMES
+// =====================
+pub const MIN_USERNAME_LENGTH: usize = 3;
+pub const MAX_USERNAME_LENGTH: usize = 20; // Also the ReferralCode seed, lowercase a-z, 0-9 and _
+pub const RESERVED_USERNAMES: &[&str] = &["admin", "fuse", "official", "support", "team", "moderator", "system"];
 
 // =====================
 // TOKEN CREATION FEE
 // =====================
 pub const CREATION_FEE_LAMPORTS: u64 = 75_000_000; // 0.075 SOL to launch a token
 
+// =====================
+// LOCKED DEV BUY
+// =====================
+pub const MAX_DEV_BUY_BPS: u64 = 500; // Creator's initial buy may take at most 5% of supply
+pub const MAX_DEV_VESTING_DURATION: i64 = 31_536_000; // 1 year max linear vesting after graduation
+
+// =====================
+// OPENING AUCTION
+// =====================
+pub const MAX_OPENING_AUCTION_DURATION: i64 = 600; // 10 minute max commit window
+
+// =====================
+// CURVE EXPIRY
+// =====================
+pub const MIN_CURVE_LIFETIME: i64 = 86_400; // Expiry at least 1 day after launch
+pub const MAX_CURVE_LIFETIME: i64 = 7_776_000; // and at most 90 days
+pub const WIND_DOWN_GRACE_PERIOD: i64 = 604_800; // 7 days of sell-only before the creator may close
+pub const WIND_DOWN_CLOSE_DEADLINE: i64 = 2_592_000; // 30 more days, then the curve closes even if holders never sold
+
+// =====================
+// POST-GRADUATION POOL FEES
+// =====================
+pub const POOL_FEE_CREATOR_SHARE_BPS: u16 = 5_000; // Creator's cut of locked LP trading fees, rest to protocol
+
+// =====================
+// CURVE-ONLY MODE
+// =====================
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey = pubkey!("9vTiDqjpS3wqi4F9XR2ZaXF2xu1Tztx4FF8fzm2Fp4tE"); // programs/fuse-hook
+
 // =====================
 // TOKEN METADATA
 // =====================
@@ -55,4 +55,160 @@ pub enum FuseError {
 
     #[msg("Unauthorized access.")]
     Unauthorized,
+
+    #[msg("Amount must be greater than zero.")]
+    InvalidAmount,
+
+    #[msg("This curve has no opening auction.")]
+    NoOpeningAuction,
+
+    #[msg("Opening auction duration is out of range.")]
+    InvalidAuctionDuration,
+
+    #[msg("Trading is disabled until the opening auction is settled.")]
+    OpeningAuctionActive,
+
+    #[msg("The opening auction commit window has closed.")]
+    OpeningAuctionClosed,
+
+    #[msg("The opening auction commit window has not ended yet.")]
+    OpeningAuctionNotEnded,
+
+    #[msg("The opening auction has already been settled.")]
+    OpeningAlreadySettled,
+
+    #[msg("The opening auction has not been settled yet.")]
+    OpeningNotSettled,
+
+    #[msg("Only curves, user profiles and referral codes can be reallocated.")]
+    AccountNotReallocatable,
+
+    #[msg("Account already has the current layout.")]
+    AccountAlreadyCurrent,
+
+    #[msg("Max wallet limit must be between 1 and 10000 basis points.")]
+    InvalidMaxWallet,
+
+    #[msg("This trade would exceed the max wallet holding limit.")]
+    MaxWalletExceeded,
+
+    #[msg("The creator's initial buy exceeds the maximum dev-buy share of supply.")]
+    DevBuyTooLarge,
+
+    #[msg("Dev-buy vesting duration is out of range.")]
+    InvalidVestingDuration,
+
+    #[msg("There is nothing to claim.")]
+    NothingToClaim,
+
+    #[msg("Curve-only transfers require a Token-2022 launch.")]
+    CurveOnlyRequiresToken2022,
+
+    #[msg("Fee split must have 1-5 unique recipients with shares summing to 10000 bps.")]
+    InvalidFeeSplit,
+
+    #[msg("The creator fee split account is missing or does not match.")]
+    FeeSplitMismatch,
+
+    #[msg("This wallet is not a creator fee recipient.")]
+    NotFeeRecipient,
+
+    #[msg("The token's Metaplex metadata account is required.")]
+    MetadataAccountMissing,
+
+    #[msg("Token metadata is locked.")]
+    MetadataLocked,
+
+    #[msg("Curve lifetime is out of range.")]
+    InvalidExpiry,
+
+    #[msg("The curve has expired; only sells are allowed.")]
+    CurveExpired,
+
+    #[msg("The curve has not expired yet.")]
+    CurveNotExpired,
+
+    #[msg("The curve is already winding down.")]
+    AlreadyWindingDown,
+
+    #[msg("The curve has reached graduation and must be migrated.")]
+    GraduationReached,
+
+    #[msg("The wind-down grace period has not ended yet.")]
+    GracePeriodActive,
+
+    #[msg("Tokens are still held outside the curve.")]
+    CurveNotEmpty,
+
+    #[msg("The launch can no longer be cancelled: someone else has traded.")]
+    LaunchAlreadyTraded,
+
+    #[msg("The creator still has locked tokens to claim.")]
+    CreatorTokensUnclaimed,
+
+    #[msg("Opening auction tokens are still unclaimed.")]
+    OpeningClaimsPending,
+
+    #[msg("Only the creator or the protocol may claim pool fees.")]
+    NotPoolFeeClaimant,
+
+    #[msg("Referral tiers must be ascending, at most 5, and pay at most 50% of the protocol fee.")]
+    InvalidReferralTiers,
+
+    #[msg("Username must be at least 3 characters.")]
+    UsernameTooShort,
+
+    #[msg("Username must be at most 20 characters.")]
+    UsernameTooLong,
+
+    #[msg("Username may only contain letters, digits and underscores.")]
+    InvalidUsernameCharacter,
+
+    #[msg("This username is reserved.")]
+    UsernameReserved,
+
+    #[msg("Referral boost must fund a positive amount at no more than 2% of volume.")]
+    InvalidReferralBoost,
+
+    #[msg("Creator fees of a curve with a fee split belong to the split.")]
+    FeeSplitFeesLocked,
+
+    #[msg("Not enough accumulated creator fees.")]
+    InsufficientCreatorFees,
+
+    #[msg("Referral binding duration must be between 0 (forever) and 2 years.")]
+    InvalidBindingDuration,
+
+    #[msg("Username codes cannot be transferred, only campaign codes.")]
+    UsernameCodeNotTransferable,
+
+    #[msg("Campaign ID 0 is reserved for username codes.")]
+    InvalidCampaignId,
+
+    #[msg("The referral code this user was bound through must be passed.")]
+    ReferralCodeRequired,
+
+    #[msg("Referral codes with referees cannot be closed.")]
+    ReferralCodeInUse,
+
+    #[msg("The curve is still trading.")]
+    CurveStillTrading,
+
+    #[msg("Graduated curves must pass their PoolFees account.")]
+    PoolFeesAccountMissing,
+
+    #[msg("This profile was closed; register again first.")]
+    ProfileClosed,
+
+    #[msg("Protocol fees must go to the protocol treasury.")]
+    InvalidTreasury,
+
+    #[msg("A creator transfer is pending; accept or cancel it first.")]
+    CreatorTransferPending,
+
+    #[msg("This referral code is already normalized.")]
+    CodeAlreadyNormalized,
+
+    #[msg("This curve has a referral boost; pass it so its budget is returned.")]
+    ReferralBoostRequired,
 }
\ No newline at end of file
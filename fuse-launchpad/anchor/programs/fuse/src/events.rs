@@ -32,6 +32,8 @@ pub struct CurveCompleted {
     pub final_market_cap: u64,
     pub total_sol_raised: u64,
     pub creator_payout: u64,
+    pub mint_authority: Option<Pubkey>,   // None once revoked
+    pub freeze_authority: Option<Pubkey>, // None once revoked (never set for new mints)
     pub timestamp: i64,
 }
 
@@ -42,3 +44,232 @@ pub struct GraduationTriggered {
     pub market_cap: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct OpeningCommitted {
+    pub mint: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub bidder_total: u64,
+    pub auction_total: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OpeningSettled {
+    pub mint: Pubkey,
+    pub total_committed: u64,
+    pub tokens_allocated: u64,
+    pub sol_refund: u64,
+    pub clearing_price: u64, // Lamports per token (scaled by 1e6)
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub real_sol_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OpeningClaimed {
+    pub mint: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub token_amount: u64,
+    pub sol_refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorTokensClaimed {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub total_released: u64,
+    pub total_locked: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorFeesClaimed {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeShareClaimed {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub share_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorTransferProposed {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub pending_creator: Option<Pubkey>, // None = proposal cancelled
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorTransferred {
+    pub mint: Pubkey,
+    pub previous_creator: Pubkey,
+    pub new_creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub mint: Pubkey,
+    pub uri: String,
+    pub locked: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CurveExpired {
+    pub mint: Pubkey,
+    pub expires_at: i64,
+    pub real_sol_reserves: u64,
+    pub real_token_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExpiredCurveClosed {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub tokens_burned: u64,
+    pub lamports_returned: u64,
+    pub unredeemed_reserves: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorLockRefunded {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub token_amount: u64,
+    pub sol_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LaunchCancelled {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub initial_buy_refund: u64,
+    pub tokens_burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GraduatedCurveClosed {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub virtual_sol_reserves: u64,  // Final curve state at graduation
+    pub virtual_token_reserves: u64,
+    pub creator_locked_tokens: u64,
+    pub launch_timestamp: i64,
+    pub graduated_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolFeesClaimed {
+    pub mint: Pubkey,
+    pub caller: Pubkey,
+    pub creator_token_amount: u64,
+    pub creator_sol_amount: u64,
+    pub protocol_token_amount: u64,
+    pub protocol_sol_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralFeesClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserRegistered {
+    pub user: Pubkey,
+    pub username: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferrerBound {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+    pub referral_code: Option<Pubkey>, // None = bound through the referrer's profile in buy
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralFeePaid {
+    pub mint: Pubkey,
+    pub trader: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub tier: u8,                   // 1 = direct referrer, 2 = referrer's referrer
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UsernameChanged {
+    pub user: Pubkey,
+    pub old_username: String,
+    pub new_username: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralBoostFunded {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub from_creator_fees: bool,
+    pub bonus_bps: u16,
+    pub budget_remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralBoostPaid {
+    pub mint: Pubkey,
+    pub trader: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub budget_remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralBoostClosed {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub budget_returned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralCodeTransferProposed {
+    pub code: String,
+    pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>, // None = proposal cancelled
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralCodeTransferred {
+    pub code: String,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
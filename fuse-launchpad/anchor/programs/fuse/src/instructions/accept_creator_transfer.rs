@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{BondingCurve, PoolFees};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::CreatorTransferred;
+
+/// AcceptCreatorTransfer - The nominee takes over the creator role
+///
+/// After migration the creator's share of LP fees moves too, so the
+/// `PoolFees` account is required once the curve is complete.
+#[derive(Accounts)]
+pub struct AcceptCreatorTransfer<'info> {
+    pub new_creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = curve_config.pending_creator == Some(new_creator.key()) @ FuseError::Unauthorized,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [POOL_FEES_SEED, mint.key().as_ref()],
+        bump = pool_fees.bump,
+    )]
+    pub pool_fees: Option<Account<'info, PoolFees>>,
+}
+
+pub fn handler(ctx: Context<AcceptCreatorTransfer>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    let previous_creator = curve_config.creator;
+
+    curve_config.creator = ctx.accounts.new_creator.key();
+    curve_config.pending_creator = None;
+
+    if curve_config.complete {
+        let pool_fees = ctx.accounts.pool_fees.as_mut().ok_or(FuseError::PoolFeesAccountMissing)?;
+        pool_fees.creator = curve_config.creator;
+    }
+
+    emit!(CreatorTransferred {
+        mint: ctx.accounts.mint.key(),
+        previous_creator,
+        new_creator: curve_config.creator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Creator: {} → {}", previous_creator, curve_config.creator);
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{ReferralCode, ReferralVault, UserProfile};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::ReferralCodeTransferred;
+
+/// AcceptReferralCode - The nominee takes over a campaign code
+///
+/// The nominee must be a registered user with a `ReferralVault`, so
+/// referral fees earned through the code have somewhere to accrue.
+#[derive(Accounts)]
+pub struct AcceptReferralCode<'info> {
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        seeds = [USER_SEED, new_owner.key().as_ref()],
+        bump = new_owner_profile.bump,
+        constraint = !new_owner_profile.username.is_empty() @ FuseError::ProfileClosed,
+    )]
+    pub new_owner_profile: Account<'info, UserProfile>,
+
+    #[account(
+        seeds = [REFERRAL_VAULT_SEED, new_owner.key().as_ref()],
+        bump = new_owner_vault.bump,
+    )]
+    pub new_owner_vault: Account<'info, ReferralVault>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, referral_code.code.as_bytes()],
+        bump = referral_code.bump,
+        constraint = referral_code.pending_owner == Some(new_owner.key()) @ FuseError::Unauthorized,
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+}
+
+pub fn handler(ctx: Context<AcceptReferralCode>) -> Result<()> {
+    let referral_code = &mut ctx.accounts.referral_code;
+    let previous_owner = referral_code.owner;
+
+    referral_code.owner = ctx.accounts.new_owner.key();
+    referral_code.pending_owner = None;
+
+    emit!(ReferralCodeTransferred {
+        code: referral_code.code.clone(),
+        previous_owner,
+        new_owner: referral_code.owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Referral code {}: {} → {}", referral_code.code, previous_owner, referral_code.owner);
+
+    Ok(())
+}
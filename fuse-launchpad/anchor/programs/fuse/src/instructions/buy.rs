@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use anchor_lang::system_program::{transfer, Transfer as SolTransfer};
-use crate::state::{BondingCurve, UserProfile};
+use crate::state::{BondingCurve, ReferralBoost, ReferralCode, ReferralConfig, ReferralVault, UserProfile};
 use crate::constants::*;
 use crate::errors::FuseError;
 use crate::events::{TradeEvent, GraduationTriggered};
+use crate::referral::{distribute_referral_fee, FeeSource, ReferralAccounts};
+use crate::transfer_interface::{self, TransferChecked};
 
+/// Buy - Swaps SOL for tokens on the curve
+///
+/// Curve-only Token-2022 launches also need the transfer hook accounts
+/// in `remaining_accounts`.
 #[derive(Accounts)]
 pub struct Buy<'info> {
     #[account(mut)]
@@ -16,10 +22,11 @@ pub struct Buy<'info> {
         seeds = [SEED, mint.key().as_ref()],
         bump = curve_config.bump,
         constraint = !curve_config.complete @ FuseError::TradingDisabled,
+        constraint = curve_config.opening_auction_end == 0 || curve_config.opening_settled @ FuseError::OpeningAuctionActive,
     )]
     pub curve_config: Account<'info, BondingCurve>,
 
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
@@ -28,14 +35,16 @@ pub struct Buy<'info> {
         token::mint = mint,
         token::authority = curve_config,
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
 
+    /// The buyer's associated token account, so the max-wallet cap holds per wallet
     #[account(
         mut,
-        token::mint = mint,
-        token::authority = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: Treasury wallet - validated by protocol
     #[account(mut)]
@@ -49,21 +58,75 @@ pub struct Buy<'info> {
     )]
     pub user_profile: Option<Account<'info, UserProfile>>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [USER_SEED, referrer_profile.authority.as_ref()],
+        bump = referrer_profile.bump,
+    )]
     pub referrer_profile: Option<Account<'info, UserProfile>>,
 
-    /// CHECK: Referrer wallet
-    #[account(mut)]
-    pub referrer_wallet: Option<AccountInfo<'info>>,
+    /// Referrer's vault, accrues the referral fee
+    #[account(
+        mut,
+        seeds = [REFERRAL_VAULT_SEED, referrer_vault.authority.as_ref()],
+        bump = referrer_vault.bump,
+    )]
+    pub referrer_vault: Option<Account<'info, ReferralVault>>,
+
+    /// Second tier: the referrer's own referrer
+    #[account(
+        mut,
+        seeds = [USER_SEED, second_referrer_profile.authority.as_ref()],
+        bump = second_referrer_profile.bump,
+    )]
+    pub second_referrer_profile: Option<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_VAULT_SEED, second_referrer_vault.authority.as_ref()],
+        bump = second_referrer_vault.bump,
+    )]
+    pub second_referrer_vault: Option<Account<'info, ReferralVault>>,
+
+    /// CHECK: Tiered referral rates and binding expiry; defaults apply
+    /// until the config is created (see `ReferralConfig::load_or_default`)
+    #[account(seeds = [REFERRAL_CONFIG_SEED], bump)]
+    pub referral_config: UncheckedAccount<'info>,
+
+    /// Code the user was (or is being) referred through, for per-code stats
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, referral_code_account.code.as_bytes()],
+        bump = referral_code_account.bump,
+    )]
+    pub referral_code_account: Option<Account<'info, ReferralCode>>,
+
+    /// Creator-funded bonus for referrers of this mint
+    #[account(
+        mut,
+        seeds = [REFERRAL_BOOST_SEED, mint.key().as_ref()],
+        bump = referral_boost.bump,
+    )]
+    pub referral_boost: Option<Account<'info, ReferralBoost>>,
 
-    pub token_program: Program<'info, Token>,
+    /// Code the referrer was referred through, to find the second tier
+    #[account(
+        seeds = [REFERRAL_SEED, second_referral_code_account.code.as_bytes()],
+        bump = second_referral_code_account.bump,
+    )]
+    pub second_referral_code_account: Option<Account<'info, ReferralCode>>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Buy>, amount_in: u64, min_tokens_out: u64) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Buy<'info>>, amount_in: u64, min_tokens_out: u64) -> Result<()> {
     let curve_config = &mut ctx.accounts.curve_config;
     let clock = Clock::get()?;
 
+    require!(!curve_config.is_expired(clock.unix_timestamp), FuseError::CurveExpired);
+
     // =====================
     // CALCULATE FEES (1%)
     // =====================
@@ -112,6 +175,17 @@ pub fn handler(ctx: Context<Buy>, amount_in: u64, min_tokens_out: u64) -> Result
         FuseError::InsufficientLiquidity
     );
 
+    // =====================
+    // MAX WALLET CHECK
+    // =====================
+    // Checked on the buyer's associated token account
+    if let Some(max_wallet) = curve_config.max_wallet_amount() {
+        let balance_after = ctx.accounts.user_token_account.amount
+            .checked_add(tokens_out)
+            .ok_or(FuseError::MathOverflow)?;
+        require!(balance_after <= max_wallet, FuseError::MaxWalletExceeded);
+    }
+
     // =====================
     // UPDATE RESERVES
     // =====================
@@ -132,76 +206,34 @@ pub fn handler(ctx: Context<Buy>, amount_in: u64, min_tokens_out: u64) -> Result
     curve_config.creator_fee_accumulated = curve_config.creator_fee_accumulated
         .checked_add(creator_fee)
         .ok_or(FuseError::MathOverflow)?;
+    curve_config.traded = true;
 
     // =====================
     // TRANSFER PROTOCOL FEE TO TREASURY (AND REFERRER)
     // =====================
-    let mut final_protocol_fee = protocol_fee;
-    let mut referral_fee = 0;
-
-    // Determine the referrer key
-    let mut active_referrer = None;
-    let mut should_bind_referrer = false;
-
-    // 1. Check if user is already bound to a referrer
-    if let Some(user_profile) = &ctx.accounts.user_profile {
-        if let Some(stored_ref) = user_profile.referrer {
-            active_referrer = Some(stored_ref);
-        }
-    }
-
-    // 2. If not bound, check if a valid referrer is provided in accounts
-    if active_referrer.is_none() {
-        if let Some(referrer_profile) = &ctx.accounts.referrer_profile {
-            // Use the authority from the provided profile
-            active_referrer = Some(referrer_profile.authority);
-            should_bind_referrer = true;
-        }
-    }
-
-    // 3. Process Referral
-    if let Some(referrer_key) = active_referrer {
-        // We need both the profile and the wallet to be present and match the key
-        if let Some(referrer_profile) = &mut ctx.accounts.referrer_profile {
-            if let Some(referrer_wallet) = &ctx.accounts.referrer_wallet {
-                
-                // Validate that the provided accounts match the active referrer key
-                if referrer_profile.authority == referrer_key && referrer_wallet.key() == referrer_key {
-                    
-                    // Bind the user if needed (and if they have a profile)
-                    if should_bind_referrer {
-                        if let Some(user_profile) = &mut ctx.accounts.user_profile {
-                            if user_profile.referrer.is_none() {
-                                user_profile.referrer = Some(referrer_key);
-                                // Increment referral count for the referrer
-                                referrer_profile.referral_count = referrer_profile.referral_count.checked_add(1).unwrap_or(referrer_profile.referral_count);
-                            }
-                        }
-                    }
-
-                    // Calculate 10% of protocol fee
-                    referral_fee = protocol_fee / 10;
-                    final_protocol_fee = protocol_fee.checked_sub(referral_fee).ok_or(FuseError::MathOverflow)?;
-
-                    // Transfer to referrer
-                    let referrer_transfer = SolTransfer {
-                        from: ctx.accounts.user.to_account_info(),
-                        to: referrer_wallet.to_account_info(),
-                    };
-                    let referrer_ctx = CpiContext::new(
-                        ctx.accounts.system_program.to_account_info(),
-                        referrer_transfer,
-                    );
-                    transfer(referrer_ctx, referral_fee)?;
-                    
-                    // Update referrer stats
-                    referrer_profile.total_referral_fees = referrer_profile.total_referral_fees.checked_add(referral_fee).unwrap_or(referrer_profile.total_referral_fees);
-                    
-                    msg!("Ref fee paid: {} to {}", referral_fee, referrer_key);
-                }
-            }
-        }
-    }
+    let final_protocol_fee = distribute_referral_fee(
+        ReferralAccounts {
+            user_profile: &mut ctx.accounts.user_profile,
+            referrer_profile: &mut ctx.accounts.referrer_profile,
+            referrer_vault: &ctx.accounts.referrer_vault,
+            second_referrer_profile: &mut ctx.accounts.second_referrer_profile,
+            second_referrer_vault: &ctx.accounts.second_referrer_vault,
+            second_referral_code_account: &ctx.accounts.second_referral_code_account,
+            referral_config: &ReferralConfig::load_or_default(&ctx.accounts.referral_config)?,
+            referral_code_account: &mut ctx.accounts.referral_code_account,
+            referral_boost: &mut ctx.accounts.referral_boost,
+        },
+        FeeSource::Wallet {
+            payer: &ctx.accounts.user.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        ctx.accounts.mint.key(),
+        ctx.accounts.user.key(),
+        protocol_fee,
+        amount_in,
+        true,
+        clock.unix_timestamp,
+    )?;
 
     if final_protocol_fee > 0 {
         let cpi_context = CpiContext::new(
@@ -236,14 +268,16 @@ pub fn handler(ctx: Context<Buy>, amount_in: u64, min_tokens_out: u64) -> Result
 
     let cpi_context_token = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.user_token_account.to_account_info(),
             authority: curve_config.to_account_info(),
         },
         signer,
-    );
-    token::transfer(cpi_context_token, tokens_out)?;
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    transfer_interface::transfer_checked(cpi_context_token, tokens_out, ctx.accounts.mint.decimals)?;
 
     // =====================
     // CALCULATE MARKET CAP
@@ -292,7 +326,7 @@ pub fn handler(ctx: Context<Buy>, amount_in: u64, min_tokens_out: u64) -> Result
 }
 
 /// Calculate current price in lamports per token (scaled by 1e6 for precision)
-fn calculate_current_price(virtual_sol: u64, virtual_tokens: u64) -> u64 {
+pub(crate) fn calculate_current_price(virtual_sol: u64, virtual_tokens: u64) -> u64 {
     // price = virtual_sol / virtual_tokens * 1_000_000 (for precision)
     ((virtual_sol as u128) * 1_000_000 / (virtual_tokens as u128)) as u64
 }
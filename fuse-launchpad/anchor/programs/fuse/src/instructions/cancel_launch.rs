@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{BondingCurve, CreatorFeeSplit, ReferralBoost};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::LaunchCancelled;
+use crate::instructions::close_expired_curve::burn_and_close_vaults;
+use crate::instructions::close_referral_boost::require_referral_boost;
+
+/// CancelLaunch - Lets the creator undo a launch nobody has traded yet
+///
+/// Only allowed while the creator's own initial buy is the sole trade.
+/// Burns the supply, closes the curve, both vaults, the fee split and any
+/// referral boost, and refunds the initial buy, the boost budget and all
+/// rent to the creator. The creation fee stays with the treasury.
+#[derive(Accounts)]
+pub struct CancelLaunch<'info> {
+    #[account(
+        mut,
+        address = curve_config.creator @ FuseError::Unauthorized,
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = !curve_config.traded @ FuseError::LaunchAlreadyTraded,
+        close = creator,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_LOCK_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub creator_lock_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Required when the launch has a fee split
+    #[account(
+        mut,
+        seeds = [FEE_SPLIT_SEED, mint.key().as_ref()],
+        bump = fee_split.bump,
+        close = creator,
+    )]
+    pub fee_split: Option<Account<'info, CreatorFeeSplit>>,
+
+    /// Required when the curve has a referral boost (closed to the creator)
+    #[account(
+        mut,
+        seeds = [REFERRAL_BOOST_SEED, mint.key().as_ref()],
+        bump = referral_boost.bump,
+        close = creator,
+    )]
+    pub referral_boost: Option<Account<'info, ReferralBoost>>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CancelLaunch>) -> Result<()> {
+    let curve_config = &ctx.accounts.curve_config;
+
+    require!(
+        !curve_config.has_fee_split || ctx.accounts.fee_split.is_some(),
+        FuseError::FeeSplitMismatch
+    );
+    require_referral_boost(curve_config, &ctx.accounts.referral_boost, ctx.accounts.creator.key())?;
+
+    let tokens_burned = ctx.accounts.vault.amount
+        .checked_add(ctx.accounts.creator_lock_vault.amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    // =====================
+    // BURN SUPPLY AND CLOSE VAULTS
+    // =====================
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[SEED, mint_key.as_ref(), &[curve_config.bump]];
+    let signer = &[&seeds[..]];
+
+    burn_and_close_vaults(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &[&ctx.accounts.vault, &ctx.accounts.creator_lock_vault],
+        &curve_config.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        signer,
+    )?;
+
+    // The initial buy's SOL leaves with the curve's rent when Anchor closes it
+    emit!(LaunchCancelled {
+        mint: mint_key,
+        creator: ctx.accounts.creator.key(),
+        initial_buy_refund: curve_config.real_sol_reserves,
+        tokens_burned,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Launch cancelled: {}", mint_key);
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::UsernameChanged;
+use crate::instructions::register_user::normalize_username;
+
+/// ChangeUsername - Renames a profile and moves its referral code
+///
+/// Closes the old `ReferralCode` (releasing the name for others, along
+/// with its counters) and creates the new one in the same instruction.
+/// An old code that referees were bound through is kept instead, so the
+/// name stays reserved and their bindings keep resolving. Campaign codes
+/// are separate accounts and stay as they are. Renaming to the current
+/// name fails, as its referral code already exists.
+#[derive(Accounts)]
+#[instruction(new_username: String)]
+pub struct ChangeUsername<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump = user_profile.bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, user_profile.username.as_bytes()],
+        bump = old_referral_code.bump,
+        constraint = old_referral_code.owner == user.key() @ FuseError::Unauthorized,
+    )]
+    pub old_referral_code: Account<'info, ReferralCode>,
+
+    #[account(
+        init,
+        payer = user,
+        seeds = [REFERRAL_SEED, normalize_username(&new_username)?.as_bytes()],
+        bump,
+        space = 8 + ReferralCode::INIT_SPACE
+    )]
+    pub new_referral_code: Account<'info, ReferralCode>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ChangeUsername>, new_username: String) -> Result<()> {
+    let new_username = normalize_username(&new_username)?;
+
+    let user_profile = &mut ctx.accounts.user_profile;
+
+    // Codes with referees are never closed, so their address can't be reused
+    if ctx.accounts.old_referral_code.referral_count == 0 {
+        ctx.accounts.old_referral_code.close(ctx.accounts.user.to_account_info())?;
+    }
+
+    let new_referral_code = &mut ctx.accounts.new_referral_code;
+    new_referral_code.owner = ctx.accounts.user.key();
+    new_referral_code.code = new_username.clone();
+    new_referral_code.campaign_id = 0;
+    new_referral_code.pending_owner = None;
+    new_referral_code.referral_count = 0;
+    new_referral_code.total_fees = 0;
+    new_referral_code.bump = ctx.bumps.new_referral_code;
+
+    msg!("Username changed: {} → {}", user_profile.username, new_username);
+    let old_username = std::mem::replace(&mut user_profile.username, new_username);
+
+    emit!(UsernameChanged {
+        user: ctx.accounts.user.key(),
+        old_username,
+        new_username: user_profile.username.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
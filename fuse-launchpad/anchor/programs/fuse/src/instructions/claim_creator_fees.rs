@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{BondingCurve, CreatorFeeSplit};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::{CreatorFeesClaimed, FeeShareClaimed};
+
+/// ClaimCreatorFees - Withdraws the creator's accumulated trading fees
+///
+/// Callable at any time, so creators of tokens that never graduate still
+/// get paid. Only the fee balance is withdrawn; the curve's SOL reserves
+/// and rent-exempt minimum are left untouched.
+///
+/// If the curve has a fee split, the fees are swept into the split and
+/// paid to every recipient, passed as writable `remaining_accounts` in
+/// the same order as `CreatorFeeSplit.recipients`.
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(
+        mut,
+        address = curve_config.creator @ FuseError::Unauthorized,
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [FEE_SPLIT_SEED, mint.key().as_ref()],
+        bump = fee_split.bump,
+    )]
+    pub fee_split: Option<Account<'info, CreatorFeeSplit>>,
+}
+
+pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    let clock = Clock::get()?;
+
+    // =====================
+    // SPLIT: SWEEP AND PAY EVERY RECIPIENT
+    // =====================
+    if curve_config.has_fee_split {
+        let fee_split = ctx.accounts.fee_split.as_mut().ok_or(FuseError::FeeSplitMismatch)?;
+        sweep_creator_fees(curve_config, fee_split)?;
+
+        require!(
+            ctx.remaining_accounts.len() == fee_split.recipients.len(),
+            FuseError::FeeSplitMismatch
+        );
+
+        let mut total_paid: u64 = 0;
+        for (index, recipient_wallet) in ctx.remaining_accounts.iter().enumerate() {
+            require!(
+                recipient_wallet.key() == fee_split.recipients[index].wallet,
+                FuseError::FeeSplitMismatch
+            );
+            let amount = pay_fee_share(fee_split, index, recipient_wallet)?;
+            total_paid = total_paid.checked_add(amount).ok_or(FuseError::MathOverflow)?;
+
+            if amount > 0 {
+                emit!(FeeShareClaimed {
+                    mint: ctx.accounts.mint.key(),
+                    recipient: recipient_wallet.key(),
+                    amount,
+                    share_bps: fee_split.recipients[index].share_bps,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+        }
+        require!(total_paid > 0, FuseError::NothingToClaim);
+
+        msg!("Creator fees split: {}", total_paid);
+        return Ok(());
+    }
+
+    // =====================
+    // PAY CREATOR
+    // =====================
+    let amount = withdrawable_creator_fees(curve_config)?;
+    require!(amount > 0, FuseError::NothingToClaim);
+
+    curve_config.creator_fee_accumulated = curve_config.creator_fee_accumulated
+        .checked_sub(amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    **curve_config.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(CreatorFeesClaimed {
+        mint: ctx.accounts.mint.key(),
+        creator: ctx.accounts.creator.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creator fees claimed: {}", amount);
+
+    Ok(())
+}
+
+/// Accumulated creator fees that can leave the curve without touching
+/// `real_sol_reserves` or the rent-exempt minimum
+pub(crate) fn withdrawable_creator_fees(curve_config: &Account<BondingCurve>) -> Result<u64> {
+    let curve_lamports = curve_config.to_account_info().lamports();
+    let rent_exempt = Rent::get()?.minimum_balance(curve_config.to_account_info().data_len());
+    let reserved = rent_exempt
+        .checked_add(curve_config.real_sol_reserves)
+        .ok_or(FuseError::MathOverflow)?;
+
+    Ok(curve_config
+        .creator_fee_accumulated
+        .min(curve_lamports.saturating_sub(reserved)))
+}
+
+/// Moves all withdrawable creator fees from the curve into the fee split
+pub(crate) fn sweep_creator_fees(
+    curve_config: &mut Account<BondingCurve>,
+    fee_split: &mut Account<CreatorFeeSplit>,
+) -> Result<u64> {
+    let amount = withdrawable_creator_fees(curve_config)?;
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    curve_config.creator_fee_accumulated = curve_config.creator_fee_accumulated
+        .checked_sub(amount)
+        .ok_or(FuseError::MathOverflow)?;
+    fee_split.total_received = fee_split.total_received
+        .checked_add(amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    **curve_config.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **fee_split.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    Ok(amount)
+}
+
+/// Pays the recipient at `index` everything they are currently owed
+pub(crate) fn pay_fee_share(
+    fee_split: &mut Account<CreatorFeeSplit>,
+    index: usize,
+    recipient_wallet: &AccountInfo,
+) -> Result<u64> {
+    let amount = fee_split.owed(index).ok_or(FuseError::MathOverflow)?;
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    let recipient = &mut fee_split.recipients[index];
+    recipient.claimed = recipient.claimed
+        .checked_add(amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    **fee_split.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **recipient_wallet.try_borrow_mut_lamports()? += amount;
+
+    Ok(amount)
+}
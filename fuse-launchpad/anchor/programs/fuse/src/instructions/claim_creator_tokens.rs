@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::BondingCurve;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::CreatorTokensClaimed;
+use crate::transfer_interface::{self, TransferChecked};
+
+/// ClaimCreatorTokens - Releases the creator's locked dev buy
+///
+/// Tokens stay in the creator-lock vault until the curve graduates. After
+/// `migrate` they unlock at once, or linearly over the vesting duration
+/// chosen at launch.
+#[derive(Accounts)]
+pub struct ClaimCreatorTokens<'info> {
+    #[account(
+        mut,
+        address = curve_config.creator @ FuseError::Unauthorized,
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = curve_config.complete @ FuseError::TokensLocked,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_LOCK_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub creator_lock_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimCreatorTokens>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    let clock = Clock::get()?;
+
+    // =====================
+    // CALCULATE VESTED AMOUNT
+    // =====================
+    let locked = curve_config.creator_locked_tokens;
+    let vested = if curve_config.creator_vesting_duration == 0 {
+        locked
+    } else {
+        let elapsed = clock.unix_timestamp
+            .saturating_sub(curve_config.graduated_at)
+            .clamp(0, curve_config.creator_vesting_duration);
+        (locked as u128)
+            .checked_mul(elapsed as u128)
+            .ok_or(FuseError::MathOverflow)?
+            .checked_div(curve_config.creator_vesting_duration as u128)
+            .ok_or(FuseError::MathOverflow)? as u64
+    };
+
+    let amount = vested
+        .checked_sub(curve_config.creator_released_tokens)
+        .ok_or(FuseError::MathOverflow)?;
+    require!(amount > 0, FuseError::NothingToClaim);
+
+    curve_config.creator_released_tokens = curve_config.creator_released_tokens
+        .checked_add(amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    // =====================
+    // TRANSFER TOKENS TO CREATOR
+    // =====================
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[SEED, mint_key.as_ref(), &[curve_config.bump]];
+    let signer = &[&seeds[..]];
+
+    // The transfer hook is cleared at graduation, so no extra accounts
+    let cpi_context_token = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.creator_lock_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.creator_token_account.to_account_info(),
+            authority: curve_config.to_account_info(),
+        },
+        signer,
+    );
+    transfer_interface::transfer_checked(cpi_context_token, amount, ctx.accounts.mint.decimals)?;
+
+    emit!(CreatorTokensClaimed {
+        mint: ctx.accounts.mint.key(),
+        creator: ctx.accounts.creator.key(),
+        amount,
+        total_released: curve_config.creator_released_tokens,
+        total_locked: locked,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
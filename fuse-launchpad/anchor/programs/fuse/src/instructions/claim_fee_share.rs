@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{BondingCurve, CreatorFeeSplit};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::FeeShareClaimed;
+use crate::instructions::claim_creator_fees::{sweep_creator_fees, pay_fee_share};
+
+/// ClaimFeeShare - Lets a single fee split recipient claim their share
+///
+/// Sweeps any new creator fees from the curve into the split first, so a
+/// recipient never has to wait for the creator or for graduation. Once the
+/// curve has been closed after graduation, it is simply left out.
+#[derive(Accounts)]
+pub struct ClaimFeeShare<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+    )]
+    pub curve_config: Option<Account<'info, BondingCurve>>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [FEE_SPLIT_SEED, mint.key().as_ref()],
+        bump = fee_split.bump,
+    )]
+    pub fee_split: Account<'info, CreatorFeeSplit>,
+}
+
+pub fn handler(ctx: Context<ClaimFeeShare>) -> Result<()> {
+    let fee_split = &mut ctx.accounts.fee_split;
+
+    let index = fee_split
+        .recipients
+        .iter()
+        .position(|r| r.wallet == ctx.accounts.recipient.key())
+        .ok_or(FuseError::NotFeeRecipient)?;
+
+    if let Some(curve_config) = ctx.accounts.curve_config.as_mut() {
+        sweep_creator_fees(curve_config, fee_split)?;
+    }
+
+    let recipient_info = ctx.accounts.recipient.to_account_info();
+    let amount = pay_fee_share(fee_split, index, &recipient_info)?;
+    require!(amount > 0, FuseError::NothingToClaim);
+
+    emit!(FeeShareClaimed {
+        mint: ctx.accounts.mint.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        share_bps: fee_split.recipients[index].share_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
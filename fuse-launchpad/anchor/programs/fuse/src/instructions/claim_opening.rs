@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{BondingCurve, OpeningAuction, OpeningBid};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::OpeningClaimed;
+use crate::transfer_interface::{self, TransferChecked};
+
+/// ClaimOpening - Pays a bidder their share of the settled opening auction
+///
+/// Tokens (and any unspent SOL from a capped settlement) are split
+/// pro-rata to committed SOL, so every bidder pays the same clearing
+/// price. The last claimer takes the rounding remainder so the auction
+/// vault always empties. The bid record is closed and its rent refunded.
+/// Curve-only Token-2022 launches also need the transfer hook accounts
+/// in `remaining_accounts`.
+#[derive(Accounts)]
+pub struct ClaimOpening<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, mint.key().as_ref()],
+        bump = opening_auction.bump,
+        constraint = opening_auction.settled @ FuseError::OpeningNotSettled,
+    )]
+    pub opening_auction: Account<'info, OpeningAuction>,
+
+    #[account(
+        mut,
+        seeds = [BID_SEED, mint.key().as_ref(), user.key().as_ref()],
+        bump = opening_bid.bump,
+        close = user,
+    )]
+    pub opening_bid: Account<'info, OpeningBid>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub auction_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimOpening<'info>>) -> Result<()> {
+    let curve_config = &ctx.accounts.curve_config;
+    let opening_auction = &mut ctx.accounts.opening_auction;
+    let amount = ctx.accounts.opening_bid.amount;
+
+    // =====================
+    // PRO-RATA SHARE
+    // =====================
+    let committed_claimed = opening_auction.committed_claimed
+        .checked_add(amount)
+        .ok_or(FuseError::MathOverflow)?;
+    let is_last_claim = committed_claimed == opening_auction.total_committed;
+
+    // token_amount = tokens_allocated * amount / total_committed
+    let (token_amount, sol_refund) = if is_last_claim {
+        (
+            opening_auction.tokens_allocated
+                .checked_sub(opening_auction.tokens_claimed)
+                .ok_or(FuseError::MathOverflow)?,
+            opening_auction.sol_refund
+                .checked_sub(opening_auction.sol_refunded)
+                .ok_or(FuseError::MathOverflow)?,
+        )
+    } else {
+        (
+            pro_rata(opening_auction.tokens_allocated, amount, opening_auction.total_committed)?,
+            pro_rata(opening_auction.sol_refund, amount, opening_auction.total_committed)?,
+        )
+    };
+
+    opening_auction.committed_claimed = committed_claimed;
+    opening_auction.tokens_claimed = opening_auction.tokens_claimed
+        .checked_add(token_amount)
+        .ok_or(FuseError::MathOverflow)?;
+    opening_auction.sol_refunded = opening_auction.sol_refunded
+        .checked_add(sol_refund)
+        .ok_or(FuseError::MathOverflow)?;
+
+    if let Some(max_wallet) = curve_config.max_wallet_amount() {
+        let balance_after = ctx.accounts.user_token_account.amount
+            .checked_add(token_amount)
+            .ok_or(FuseError::MathOverflow)?;
+        require!(balance_after <= max_wallet, FuseError::MaxWalletExceeded);
+    }
+
+    // =====================
+    // TRANSFER TOKENS TO USER
+    // =====================
+    if token_amount > 0 {
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[SEED, mint_key.as_ref(), &[curve_config.bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_context_token = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.auction_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: curve_config.to_account_info(),
+            },
+            signer,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+        transfer_interface::transfer_checked(cpi_context_token, token_amount, ctx.accounts.mint.decimals)?;
+    }
+
+    // =====================
+    // REFUND UNSPENT SOL
+    // =====================
+    if sol_refund > 0 {
+        **opening_auction.to_account_info().try_borrow_mut_lamports()? -= sol_refund;
+        **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += sol_refund;
+    }
+
+    emit!(OpeningClaimed {
+        mint: ctx.accounts.mint.key(),
+        bidder: ctx.accounts.user.key(),
+        amount,
+        token_amount,
+        sol_refund,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+fn pro_rata(total: u64, amount: u64, total_committed: u64) -> Result<u64> {
+    Ok((total as u128)
+        .checked_mul(amount as u128)
+        .ok_or(FuseError::MathOverflow)?
+        .checked_div(total_committed as u128)
+        .ok_or(FuseError::MathOverflow)? as u64)
+}
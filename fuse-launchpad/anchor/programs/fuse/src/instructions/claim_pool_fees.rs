@@ -0,0 +1,285 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{spl_token::native_mint, Token};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::PoolFees;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::PoolFeesClaimed;
+use crate::meteora_interface::{self, dynamic_amm, dynamic_vault, ClaimFee, LOCK_ESCROW_SEED};
+
+/// ClaimPoolFees - Collects trading fees from the locked Meteora LP
+///
+/// After migration the bot locks the LP in an escrow owned by the
+/// `PoolFees` PDA (see `migrate`). Either the creator or the protocol can trigger a claim: the fees
+/// (graduated token and WSOL) land in the PDA's token accounts and are
+/// immediately split by `creator_share_bps` between both parties.
+#[derive(Accounts)]
+pub struct ClaimPoolFees<'info> {
+    #[account(
+        mut,
+        constraint = caller.key() == pool_fees.creator
+            || caller.key() == pool_fees.protocol_authority @ FuseError::NotPoolFeeClaimant,
+    )]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_FEES_SEED, mint.key().as_ref()],
+        bump = pool_fees.bump,
+    )]
+    pub pool_fees: Account<'info, PoolFees>,
+
+    /// The graduated token (token A of the pool)
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(address = native_mint::ID)]
+    pub wsol_mint: InterfaceAccount<'info, Mint>,
+
+    // ====================================================
+    // FEE ACCOUNTS
+    // ====================================================
+    /// Receives the claimed token A fees before the split
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = pool_fees,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Receives the claimed WSOL fees before the split
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = wsol_mint,
+        associated_token::authority = pool_fees,
+        associated_token::token_program = wsol_token_program,
+    )]
+    pub fee_wsol_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = pool_fees.creator,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = pool_fees.creator,
+    )]
+    pub creator_wsol_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = pool_fees.protocol_authority,
+    )]
+    pub protocol_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = pool_fees.protocol_authority,
+    )]
+    pub protocol_wsol_account: InterfaceAccount<'info, TokenAccount>,
+
+    // ====================================================
+    // METEORA DYNAMIC AMM ACCOUNTS
+    // ====================================================
+    /// CHECK: Meteora pool created at migration
+    #[account(mut, address = pool_fees.pool, owner = dynamic_amm::ID)]
+    pub meteora_pool: AccountInfo<'info>,
+
+    /// CHECK: LP mint for the Meteora pool
+    #[account(mut)]
+    pub lp_mint: AccountInfo<'info>,
+
+    /// CHECK: Lock escrow owned by pool_fees, checked by its Meteora derivation
+    #[account(
+        mut,
+        address = pool_fees.lock_escrow,
+        owner = dynamic_amm::ID,
+        seeds = [LOCK_ESCROW_SEED, meteora_pool.key().as_ref(), pool_fees.key().as_ref()],
+        bump,
+        seeds::program = dynamic_amm::ID,
+    )]
+    pub lock_escrow: AccountInfo<'info>,
+
+    /// Escrow vault holding the locked LP
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = lock_escrow,
+        associated_token::token_program = wsol_token_program,
+    )]
+    pub escrow_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The PDA's own LP account, Meteora withdraws the fee LP through it
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = lp_mint,
+        associated_token::authority = pool_fees,
+        associated_token::token_program = wsol_token_program,
+    )]
+    pub fee_lp_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Meteora vault for token A
+    #[account(mut)]
+    pub a_vault: AccountInfo<'info>,
+
+    /// CHECK: Meteora vault for token B (WSOL)
+    #[account(mut)]
+    pub b_vault: AccountInfo<'info>,
+
+    /// CHECK: Token vault of vault A
+    #[account(mut)]
+    pub a_token_vault: AccountInfo<'info>,
+
+    /// CHECK: Token vault of vault B
+    #[account(mut)]
+    pub b_token_vault: AccountInfo<'info>,
+
+    /// CHECK: LP mint of vault A
+    #[account(mut)]
+    pub a_vault_lp_mint: AccountInfo<'info>,
+
+    /// CHECK: LP mint of vault B
+    #[account(mut)]
+    pub b_vault_lp_mint: AccountInfo<'info>,
+
+    /// CHECK: LP token account of vault A
+    #[account(mut)]
+    pub a_vault_lp: AccountInfo<'info>,
+
+    /// CHECK: LP token account of vault B
+    #[account(mut)]
+    pub b_vault_lp: AccountInfo<'info>,
+
+    // ====================================================
+    // PROGRAMS
+    // ====================================================
+    pub token_program: Interface<'info, TokenInterface>,
+    pub wsol_token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Meteora Dynamic AMM program
+    #[account(address = dynamic_amm::ID)]
+    pub dynamic_amm_program: AccountInfo<'info>,
+
+    /// CHECK: Meteora Dynamic Vault program
+    #[account(address = dynamic_vault::ID)]
+    pub vault_program: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimPoolFees>) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let bump = ctx.accounts.pool_fees.bump;
+    let seeds = &[POOL_FEES_SEED, mint_key.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+
+    // =====================
+    // 1. CLAIM FROM LOCK ESCROW (CPI)
+    // =====================
+    let claim_accounts = ClaimFee {
+        pool: ctx.accounts.meteora_pool.clone(),
+        lp_mint: ctx.accounts.lp_mint.clone(),
+        lock_escrow: ctx.accounts.lock_escrow.clone(),
+        owner: ctx.accounts.pool_fees.to_account_info(),
+        source_tokens: ctx.accounts.fee_lp_account.to_account_info(),
+        escrow_vault: ctx.accounts.escrow_vault.to_account_info(),
+        token_program: ctx.accounts.wsol_token_program.to_account_info(),
+        a_token_vault: ctx.accounts.a_token_vault.clone(),
+        b_token_vault: ctx.accounts.b_token_vault.clone(),
+        a_vault: ctx.accounts.a_vault.clone(),
+        b_vault: ctx.accounts.b_vault.clone(),
+        a_vault_lp: ctx.accounts.a_vault_lp.clone(),
+        b_vault_lp: ctx.accounts.b_vault_lp.clone(),
+        a_vault_lp_mint: ctx.accounts.a_vault_lp_mint.clone(),
+        b_vault_lp_mint: ctx.accounts.b_vault_lp_mint.clone(),
+        user_a_token: ctx.accounts.fee_token_account.to_account_info(),
+        user_b_token: ctx.accounts.fee_wsol_account.to_account_info(),
+        vault_program: ctx.accounts.vault_program.clone(),
+    };
+
+    meteora_interface::claim_fee(
+        CpiContext::new_with_signer(ctx.accounts.dynamic_amm_program.clone(), claim_accounts, signer),
+        u64::MAX,
+    )?;
+
+    ctx.accounts.fee_token_account.reload()?;
+    ctx.accounts.fee_wsol_account.reload()?;
+
+    // =====================
+    // 2. SPLIT BETWEEN CREATOR AND PROTOCOL
+    // =====================
+    // The whole balance is split, so anything sent to these accounts
+    // outside a claim is shared too
+    let token_amount = ctx.accounts.fee_token_account.amount;
+    let sol_amount = ctx.accounts.fee_wsol_account.amount;
+
+    let pool_fees = &ctx.accounts.pool_fees;
+    let (creator_token_amount, protocol_token_amount) = pool_fees.split(token_amount)
+        .ok_or(FuseError::MathOverflow)?;
+    let (creator_sol_amount, protocol_sol_amount) = pool_fees.split(sol_amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    let authority = pool_fees.to_account_info();
+    let payouts = [
+        (&ctx.accounts.token_program.to_account_info(), &ctx.accounts.fee_token_account, &ctx.accounts.mint, &ctx.accounts.creator_token_account, creator_token_amount),
+        (&ctx.accounts.token_program.to_account_info(), &ctx.accounts.fee_token_account, &ctx.accounts.mint, &ctx.accounts.protocol_token_account, protocol_token_amount),
+        (&ctx.accounts.wsol_token_program.to_account_info(), &ctx.accounts.fee_wsol_account, &ctx.accounts.wsol_mint, &ctx.accounts.creator_wsol_account, creator_sol_amount),
+        (&ctx.accounts.wsol_token_program.to_account_info(), &ctx.accounts.fee_wsol_account, &ctx.accounts.wsol_mint, &ctx.accounts.protocol_wsol_account, protocol_sol_amount),
+    ];
+
+    for (token_program, from, mint, to, amount) in payouts {
+        if amount == 0 {
+            continue;
+        }
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: authority.clone(),
+                },
+                signer,
+            ),
+            amount,
+            mint.decimals,
+        )?;
+    }
+
+    // =====================
+    // 3. RECORD AND EMIT
+    // =====================
+    let pool_fees = &mut ctx.accounts.pool_fees;
+    pool_fees.total_token_claimed = pool_fees.total_token_claimed
+        .checked_add(token_amount)
+        .ok_or(FuseError::MathOverflow)?;
+    pool_fees.total_sol_claimed = pool_fees.total_sol_claimed
+        .checked_add(sol_amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    emit!(PoolFeesClaimed {
+        mint: mint_key,
+        caller: ctx.accounts.caller.key(),
+        creator_token_amount,
+        creator_sol_amount,
+        protocol_token_amount,
+        protocol_sol_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Pool fees claimed: {} tokens, {} WSOL", token_amount, sol_amount);
+
+    Ok(())
+}
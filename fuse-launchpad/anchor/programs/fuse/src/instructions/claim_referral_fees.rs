@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{ReferralVault, UserProfile};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::ReferralFeesClaimed;
+
+/// ClaimReferralFees - Withdraws a referrer's accrued referral fees
+///
+/// Trades deposit referral fees into the referrer's `ReferralVault`
+/// instead of paying the wallet directly. Only the unclaimed balance
+/// tracked on the profile is withdrawn; the vault's rent stays put.
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, referrer.key().as_ref()],
+        bump = user_profile.bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_VAULT_SEED, referrer.key().as_ref()],
+        bump = referral_vault.bump,
+    )]
+    pub referral_vault: Account<'info, ReferralVault>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let user_profile = &mut ctx.accounts.user_profile;
+    let amount = user_profile.unclaimed_referral_fees;
+    require!(amount > 0, FuseError::NothingToClaim);
+
+    **ctx.accounts.referral_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    user_profile.unclaimed_referral_fees = 0;
+    user_profile.claimed_referral_fees = user_profile.claimed_referral_fees
+        .checked_add(amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    emit!(ReferralFeesClaimed {
+        referrer: ctx.accounts.referrer.key(),
+        amount,
+        total_claimed: user_profile.claimed_referral_fees,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Referral fees claimed: {}", amount);
+
+    Ok(())
+}
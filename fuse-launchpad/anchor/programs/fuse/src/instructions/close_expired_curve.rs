@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, CloseAccount, Mint, TokenAccount, TokenInterface};
+use crate::state::{BondingCurve, CreatorFeeSplit, ReferralBoost};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::ExpiredCurveClosed;
+use crate::instructions::claim_creator_fees::sweep_creator_fees;
+use crate::instructions::close_referral_boost::require_referral_boost;
+
+/// CloseExpiredCurve - Shuts down an emptied curve after wind-down
+///
+/// Callable by the creator once the grace period has passed and the
+/// tokens still held outside the curve would redeem nothing (holders have
+/// sold, down to dust), or unconditionally once `WIND_DOWN_CLOSE_DEADLINE`
+/// has also passed. Whatever the vaults (and an unclaimed opening auction
+/// vault) hold is burned and they are closed. SOL the remaining holders
+/// never redeemed goes to the treasury; the creator only gets the rent and
+/// their unpaid fees. Curves with a fee split sweep the unpaid fees into
+/// the split first. A referral boost is closed with the curve, returning
+/// its budget to the creator. The creator's locked buy can be sold back
+/// first via `refund_creator_lock`.
+#[derive(Accounts)]
+pub struct CloseExpiredCurve<'info> {
+    #[account(
+        mut,
+        address = curve_config.creator @ FuseError::Unauthorized,
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = curve_config.wind_down_at > 0 @ FuseError::CurveNotExpired,
+        close = creator,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_LOCK_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub creator_lock_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Required when the curve settled an opening auction
+    #[account(
+        mut,
+        seeds = [AUCTION_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub auction_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [FEE_SPLIT_SEED, mint.key().as_ref()],
+        bump = fee_split.bump,
+    )]
+    pub fee_split: Option<Account<'info, CreatorFeeSplit>>,
+
+    /// CHECK: Protocol treasury, receives the unredeemed reserves
+    #[account(mut, address = TREASURY @ FuseError::InvalidTreasury)]
+    pub treasury: AccountInfo<'info>,
+
+    /// Required when the curve has a referral boost (closed to the creator)
+    #[account(
+        mut,
+        seeds = [REFERRAL_BOOST_SEED, mint.key().as_ref()],
+        bump = referral_boost.bump,
+        close = creator,
+    )]
+    pub referral_boost: Option<Account<'info, ReferralBoost>>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CloseExpiredCurve>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    let clock = Clock::get()?;
+
+    // =====================
+    // VALIDATION
+    // =====================
+    let grace_end = curve_config.wind_down_at
+        .checked_add(WIND_DOWN_GRACE_PERIOD)
+        .ok_or(FuseError::MathOverflow)?;
+    require!(clock.unix_timestamp >= grace_end, FuseError::GracePeriodActive);
+
+    let mut vaults = vec![&ctx.accounts.vault, &ctx.accounts.creator_lock_vault];
+    if curve_config.opening_settled {
+        vaults.push(ctx.accounts.auction_vault.as_ref().ok_or(FuseError::OpeningClaimsPending)?);
+    }
+    let tokens_in_vaults = vaults.iter().try_fold(0u64, |total, vault| total.checked_add(vault.amount))
+        .ok_or(FuseError::MathOverflow)?;
+
+    // Dust left with holders (or unclaimed in the auction vault) doesn't
+    // block the close as long as selling it would return nothing
+    let outstanding = curve_config.token_total_supply.saturating_sub(
+        ctx.accounts.vault.amount.saturating_add(ctx.accounts.creator_lock_vault.amount)
+    );
+    let redeemable = (curve_config.virtual_sol_reserves as u128)
+        .checked_mul(outstanding as u128)
+        .ok_or(FuseError::MathOverflow)?
+        .checked_div(
+            (curve_config.virtual_token_reserves as u128)
+                .checked_add(outstanding as u128)
+                .ok_or(FuseError::MathOverflow)?
+        )
+        .ok_or(FuseError::MathOverflow)?
+        .min(curve_config.real_sol_reserves as u128);
+    let close_deadline = grace_end
+        .checked_add(WIND_DOWN_CLOSE_DEADLINE)
+        .ok_or(FuseError::MathOverflow)?;
+    require!(
+        redeemable == 0 || clock.unix_timestamp >= close_deadline,
+        FuseError::CurveNotEmpty
+    );
+    require_referral_boost(curve_config, &ctx.accounts.referral_boost, ctx.accounts.creator.key())?;
+
+    // =====================
+    // SETTLE SPLIT FEES
+    // =====================
+    if curve_config.has_fee_split {
+        let fee_split = ctx.accounts.fee_split.as_mut().ok_or(FuseError::FeeSplitMismatch)?;
+        sweep_creator_fees(curve_config, fee_split)?;
+    }
+
+    // =====================
+    // UNREDEEMED RESERVES TO TREASURY
+    // =====================
+    // Holders' SOL never belongs to the creator
+    let unredeemed_reserves = curve_config.real_sol_reserves;
+    if unredeemed_reserves > 0 {
+        **curve_config.to_account_info().try_borrow_mut_lamports()? -= unredeemed_reserves;
+        **ctx.accounts.treasury.try_borrow_mut_lamports()? += unredeemed_reserves;
+        curve_config.real_sol_reserves = 0;
+    }
+
+    // =====================
+    // BURN SUPPLY AND CLOSE VAULTS
+    // =====================
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[SEED, mint_key.as_ref(), &[curve_config.bump]];
+    let signer = &[&seeds[..]];
+
+    burn_and_close_vaults(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &vaults,
+        &curve_config.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        signer,
+    )?;
+
+    // The curve account itself is closed to the creator by Anchor
+    let lamports_returned = curve_config.to_account_info().lamports();
+
+    emit!(ExpiredCurveClosed {
+        mint: mint_key,
+        creator: ctx.accounts.creator.key(),
+        tokens_burned: tokens_in_vaults,
+        lamports_returned,
+        unredeemed_reserves,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Expired curve closed: {} l → creator, {} l → treasury", lamports_returned, unredeemed_reserves);
+
+    Ok(())
+}
+
+/// Burns whatever the curve's vaults hold and closes them, sending their
+/// rent to `destination`
+pub(crate) fn burn_and_close_vaults<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vaults: &[&InterfaceAccount<'info, TokenAccount>],
+    curve: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    for vault in vaults {
+        if vault.amount > 0 {
+            token_interface::burn(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Burn {
+                        mint: mint.to_account_info(),
+                        from: vault.to_account_info(),
+                        authority: curve.clone(),
+                    },
+                    signer,
+                ),
+                vault.amount,
+            )?;
+        }
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: vault.to_account_info(),
+                destination: destination.clone(),
+                authority: curve.clone(),
+            },
+            signer,
+        ))?;
+    }
+
+    Ok(())
+}
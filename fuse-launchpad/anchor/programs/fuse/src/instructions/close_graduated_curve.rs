@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface};
+use crate::state::{BondingCurve, OpeningAuction, ReferralBoost};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::GraduatedCurveClosed;
+use crate::instructions::close_expired_curve::burn_and_close_vaults;
+use crate::instructions::close_referral_boost::require_referral_boost;
+
+/// CloseGraduatedCurve - Reclaims the rent of a migrated curve
+///
+/// Permissionless once the creator has received their whole locked buy,
+/// every opening auction bid has been claimed (`claim_opening` needs the
+/// curve) and no creator transfer is pending. Closes the emptied vault,
+/// the creator-lock vault, the auction vault (if any) and the curve
+/// itself, returning all rent to the creator. The curve's referral boost,
+/// if it has one, must be passed and is closed to the creator with its
+/// remaining budget.
+/// The final curve stats are kept in the `GraduatedCurveClosed` event.
+///
+/// Fee split recipients can still claim afterwards: `claim_fee_share`
+/// works without the curve once it is closed.
+#[derive(Accounts)]
+pub struct CloseGraduatedCurve<'info> {
+    pub caller: Signer<'info>,
+
+    /// CHECK: Receives the rent. Must match curve_config.creator.
+    #[account(mut, address = curve_config.creator @ FuseError::Unauthorized)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = curve_config.complete @ FuseError::GraduationNotReached,
+        constraint = curve_config.pending_creator.is_none() @ FuseError::CreatorTransferPending,
+        close = creator,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_LOCK_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub creator_lock_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Required when the curve settled an opening auction
+    #[account(
+        seeds = [AUCTION_SEED, mint.key().as_ref()],
+        bump = opening_auction.bump,
+    )]
+    pub opening_auction: Option<Account<'info, OpeningAuction>>,
+
+    /// Required when the curve settled an opening auction
+    #[account(
+        mut,
+        seeds = [AUCTION_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub auction_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Required when the curve has a referral boost (closed to the creator)
+    #[account(
+        mut,
+        seeds = [REFERRAL_BOOST_SEED, mint.key().as_ref()],
+        bump = referral_boost.bump,
+        close = creator,
+    )]
+    pub referral_boost: Option<Account<'info, ReferralBoost>>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CloseGraduatedCurve>) -> Result<()> {
+    let curve_config = &ctx.accounts.curve_config;
+
+    // =====================
+    // VALIDATION
+    // =====================
+    require!(
+        curve_config.creator_released_tokens == curve_config.creator_locked_tokens,
+        FuseError::CreatorTokensUnclaimed
+    );
+
+    require_referral_boost(curve_config, &ctx.accounts.referral_boost, curve_config.creator)?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[SEED, mint_key.as_ref(), &[curve_config.bump]];
+    let signer = &[&seeds[..]];
+
+    // =====================
+    // CLOSE AUCTION VAULT
+    // =====================
+    if curve_config.opening_settled {
+        // Bids are never zero, so the claimed amount only reaches the
+        // committed total once every bid has been claimed
+        let opening_auction = ctx.accounts.opening_auction.as_ref().ok_or(FuseError::OpeningClaimsPending)?;
+        require!(
+            opening_auction.committed_claimed == opening_auction.total_committed,
+            FuseError::OpeningClaimsPending
+        );
+
+        let auction_vault = ctx.accounts.auction_vault.as_ref().ok_or(FuseError::OpeningClaimsPending)?;
+        require!(auction_vault.amount == 0, FuseError::OpeningClaimsPending);
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: auction_vault.to_account_info(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: curve_config.to_account_info(),
+            },
+            signer,
+        ))?;
+    }
+
+    // =====================
+    // CLOSE CURVE VAULTS
+    // =====================
+    // Both are empty unless someone sent tokens to them after migration
+    burn_and_close_vaults(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &[&ctx.accounts.vault, &ctx.accounts.creator_lock_vault],
+        &curve_config.to_account_info(),
+        &ctx.accounts.creator,
+        signer,
+    )?;
+
+    emit!(GraduatedCurveClosed {
+        mint: mint_key,
+        creator: curve_config.creator,
+        virtual_sol_reserves: curve_config.virtual_sol_reserves,
+        virtual_token_reserves: curve_config.virtual_token_reserves,
+        creator_locked_tokens: curve_config.creator_locked_tokens,
+        launch_timestamp: curve_config.launch_timestamp,
+        graduated_at: curve_config.graduated_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Graduated curve closed: {}", mint_key);
+
+    Ok(())
+}
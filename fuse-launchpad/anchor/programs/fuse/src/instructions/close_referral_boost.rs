@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{BondingCurve, ReferralBoost};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::ReferralBoostClosed;
+
+/// CloseReferralBoost - Creator reclaims an unspent referral boost
+///
+/// Only once the curve has stopped trading (graduated, expired or winding
+/// down). The remaining budget and the account's rent go to the creator.
+#[derive(Accounts)]
+pub struct CloseReferralBoost<'info> {
+    #[account(
+        mut,
+        address = curve_config.creator @ FuseError::Unauthorized,
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_BOOST_SEED, mint.key().as_ref()],
+        bump = referral_boost.bump,
+        close = creator,
+    )]
+    pub referral_boost: Account<'info, ReferralBoost>,
+}
+
+pub fn handler(ctx: Context<CloseReferralBoost>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    let clock = Clock::get()?;
+
+    require!(
+        curve_config.complete
+            || curve_config.wind_down_at > 0
+            || curve_config.is_expired(clock.unix_timestamp),
+        FuseError::CurveStillTrading
+    );
+    curve_config.has_referral_boost = false;
+
+    let budget_returned = ctx.accounts.referral_boost.budget_remaining;
+
+    emit!(ReferralBoostClosed {
+        mint: ctx.accounts.mint.key(),
+        creator: ctx.accounts.creator.key(),
+        budget_returned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Referral boost closed: {} returned", budget_returned);
+
+    Ok(())
+}
+
+/// Curve-closing instructions close the boost to the creator as well
+/// (Anchor `close`); this makes sure it was passed when the curve has one
+/// and reports the returned budget
+pub(crate) fn require_referral_boost(
+    curve_config: &BondingCurve,
+    referral_boost: &Option<Account<ReferralBoost>>,
+    creator: Pubkey,
+) -> Result<()> {
+    if !curve_config.has_referral_boost {
+        return Ok(());
+    }
+    let referral_boost = referral_boost.as_ref().ok_or(FuseError::ReferralBoostRequired)?;
+
+    emit!(ReferralBoostClosed {
+        mint: referral_boost.mint,
+        creator,
+        budget_returned: referral_boost.budget_remaining,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
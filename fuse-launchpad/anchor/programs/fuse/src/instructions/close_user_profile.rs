@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::FuseError;
+
+/// CloseUserProfile - Deletes a profile and returns its rent to the user
+///
+/// Closes the profile, its username `ReferralCode` and its `ReferralVault`
+/// (unclaimed referral fees go out with the vault's lamports). Only a
+/// small `ClosedProfile` marker stays behind with the referrer binding,
+/// which `register_user` restores, so registering again can't rebind
+/// early or count the wallet as a new referee twice. Campaign
+/// codes can be closed in the same call by passing them as writable
+/// `remaining_accounts`. Codes that referees were bound through are
+/// never closed: the username code is left in place, and campaign codes
+/// with referees are rejected.
+///
+/// Referees bound to this wallet keep trading normally: without the
+/// referrer's profile and vault no referral fee is taken, so the full
+/// protocol fee goes to the treasury.
+#[derive(Accounts)]
+pub struct CloseUserProfile<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump = user_profile.bump,
+        constraint = !user_profile.username.is_empty() @ FuseError::ProfileClosed,
+        close = user,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// Keeps the referrer binding until the wallet registers again
+    #[account(
+        init,
+        payer = user,
+        seeds = [CLOSED_PROFILE_SEED, user.key().as_ref()],
+        bump,
+        space = 8 + ClosedProfile::INIT_SPACE
+    )]
+    pub closed_profile: Account<'info, ClosedProfile>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, user_profile.username.as_bytes()],
+        bump = referral_code.bump,
+        constraint = referral_code.owner == user.key() @ FuseError::Unauthorized,
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_VAULT_SEED, user.key().as_ref()],
+        bump = referral_vault.bump,
+        close = user,
+    )]
+    pub referral_vault: Account<'info, ReferralVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseUserProfile<'info>>) -> Result<()> {
+    // =====================
+    // CLOSE CAMPAIGN CODES
+    // =====================
+    for account in ctx.remaining_accounts.iter() {
+        let campaign_code: Account<'info, ReferralCode> = Account::try_from(account)?;
+        require!(campaign_code.owner == ctx.accounts.user.key(), FuseError::Unauthorized);
+        require!(campaign_code.referral_count == 0, FuseError::ReferralCodeInUse);
+        campaign_code.close(ctx.accounts.user.to_account_info())?;
+    }
+
+    // A username code with referees stays, so its address can't be reused
+    if ctx.accounts.referral_code.referral_count == 0 {
+        ctx.accounts.referral_code.close(ctx.accounts.user.to_account_info())?;
+    }
+
+    // =====================
+    // KEEP THE REFERRER BINDING
+    // =====================
+    let user_profile = &ctx.accounts.user_profile;
+    let closed_profile = &mut ctx.accounts.closed_profile;
+    closed_profile.referrer = user_profile.referrer;
+    closed_profile.referrer_bound_at = user_profile.referrer_bound_at;
+    closed_profile.referred_via = user_profile.referred_via;
+    closed_profile.bump = ctx.bumps.closed_profile;
+
+    msg!(
+        "Profile closed: {} ({} referral fees left the vault)",
+        user_profile.username,
+        user_profile.unclaimed_referral_fees
+    );
+
+    Ok(())
+}
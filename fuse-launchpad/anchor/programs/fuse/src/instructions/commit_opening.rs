@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer as SolTransfer};
+use anchor_spl::token_interface::Mint;
+use crate::state::{BondingCurve, OpeningAuction, OpeningBid};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::OpeningCommitted;
+use crate::instructions::create_token::calculate_tokens_out;
+
+/// CommitOpening - Deposits SOL into the curve's opening auction escrow
+///
+/// Bids are only accepted while the commit window is open. All bids are
+/// later cleared at the same price by `settle_opening`, so landing first
+/// gives no advantage.
+#[derive(Accounts)]
+pub struct CommitOpening<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = curve_config.opening_auction_end > 0 @ FuseError::NoOpeningAuction,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Auction escrow PDA - holds all committed SOL until settlement
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [AUCTION_SEED, mint.key().as_ref()],
+        bump,
+        space = 8 + OpeningAuction::INIT_SPACE
+    )]
+    pub opening_auction: Account<'info, OpeningAuction>,
+
+    /// The user's bid record for this auction
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [BID_SEED, mint.key().as_ref(), user.key().as_ref()],
+        bump,
+        space = 8 + OpeningBid::INIT_SPACE
+    )]
+    pub opening_bid: Account<'info, OpeningBid>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CommitOpening>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(amount > 0, FuseError::InvalidAmount);
+    require!(
+        !ctx.accounts.curve_config.is_expired(clock.unix_timestamp),
+        FuseError::CurveExpired
+    );
+    require!(
+        clock.unix_timestamp < ctx.accounts.curve_config.opening_auction_end,
+        FuseError::OpeningAuctionClosed
+    );
+
+    // =====================
+    // TRANSFER SOL TO AUCTION ESCROW
+    // =====================
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            SolTransfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.opening_auction.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // =====================
+    // RECORD BID
+    // =====================
+    ctx.accounts.curve_config.traded = true;
+
+    let opening_auction = &mut ctx.accounts.opening_auction;
+    opening_auction.mint = ctx.accounts.mint.key();
+    opening_auction.bump = ctx.bumps.opening_auction;
+    opening_auction.total_committed = opening_auction.total_committed
+        .checked_add(amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    let opening_bid = &mut ctx.accounts.opening_bid;
+    opening_bid.bidder = ctx.accounts.user.key();
+    opening_bid.bump = ctx.bumps.opening_bid;
+    opening_bid.amount = opening_bid.amount
+        .checked_add(amount)
+        .ok_or(FuseError::MathOverflow)?;
+
+    // =====================
+    // MAX WALLET CHECK
+    // =====================
+    // A bid never clears more tokens than it would buy alone at today's
+    // price, so capping that amount keeps every claim within the limit
+    if let Some(max_wallet) = ctx.accounts.curve_config.max_wallet_amount() {
+        let bid_fee = opening_bid.amount
+            .checked_mul(FEE_BASIS_POINTS)
+            .ok_or(FuseError::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(FuseError::MathOverflow)?;
+        let max_tokens = calculate_tokens_out(
+            ctx.accounts.curve_config.virtual_sol_reserves,
+            ctx.accounts.curve_config.virtual_token_reserves,
+            opening_bid.amount - bid_fee,
+        )?;
+        require!(max_tokens <= max_wallet, FuseError::MaxWalletExceeded);
+    }
+
+    emit!(OpeningCommitted {
+        mint: ctx.accounts.mint.key(),
+        bidder: ctx.accounts.user.key(),
+        amount,
+        bidder_total: opening_bid.amount,
+        auction_total: opening_auction.total_committed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
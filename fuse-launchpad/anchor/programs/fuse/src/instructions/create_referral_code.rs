@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::instructions::register_user::normalize_username;
+
+/// CreateReferralCode - Adds a campaign code pointing to the caller's profile
+///
+/// Lets affiliates hand out one code per channel and compare conversions.
+/// Codes share the username namespace and rules; referrals and fees are
+/// counted per code.
+#[derive(Accounts)]
+#[instruction(code: String)]
+pub struct CreateReferralCode<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump = user_profile.bump,
+        constraint = !user_profile.username.is_empty() @ FuseError::ProfileClosed,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init,
+        payer = user,
+        seeds = [REFERRAL_SEED, normalize_username(&code)?.as_bytes()],
+        bump,
+        space = 8 + ReferralCode::INIT_SPACE
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateReferralCode>, code: String, campaign_id: u16) -> Result<()> {
+    let code = normalize_username(&code)?;
+    require!(campaign_id != 0, FuseError::InvalidCampaignId);
+
+    let referral_code = &mut ctx.accounts.referral_code;
+    referral_code.owner = ctx.accounts.user.key();
+    referral_code.code = code;
+    referral_code.campaign_id = campaign_id;
+    referral_code.pending_owner = None;
+    referral_code.referral_count = 0;
+    referral_code.total_fees = 0;
+    referral_code.bump = ctx.bumps.referral_code;
+
+    msg!("Referral code created: {} (campaign {})", referral_code.code, campaign_id);
+
+    Ok(())
+}
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
-use anchor_spl::associated_token::AssociatedToken;
-use crate::state::BondingCurve;
+use crate::state::{BondingCurve, CreatorFeeSplit, FeeRecipient};
 use crate::constants::*;
 use crate::errors::FuseError;
 use crate::events::CurveInitialized;
+use crate::meteora_interface::mpl_token_metadata;
+use crate::metadata_interface::{self, CreateMetadataAccountsV3, DataV2};
 
 /// CreateToken - The main entry point for launching a new token
 /// 
@@ -12,7 +13,10 @@ use crate::events::CurveInitialized;
 /// 1. Creates a new SPL token mint
 /// 2. Initializes the bonding curve PDA with virtual reserves
 /// 3. Mints the total supply to the curve's vault
-/// 4. Optionally performs an initial buy for the creator
+///    and creates its Metaplex metadata (update authority: curve PDA)
+/// 4. Optionally performs an initial buy for the creator (locked until graduation)
+/// 5. Optionally opens a batch auction before public trading
+/// 6. Optionally splits the creator fee between several wallets
 #[derive(Accounts)]
 #[instruction(name: String, symbol: String, uri: String)]
 pub struct CreateToken<'info> {
@@ -31,12 +35,12 @@ pub struct CreateToken<'info> {
     pub curve: Account<'info, BondingCurve>,
 
     /// The new token mint (created by this instruction)
+    /// No freeze authority is ever set; mint authority is revoked at graduation
     #[account(
         init,
         payer = creator,
         mint::decimals = TOKEN_DECIMALS,
         mint::authority = curve,
-        mint::freeze_authority = curve,
     )]
     pub mint: Account<'info, Mint>,
 
@@ -51,14 +55,35 @@ pub struct CreateToken<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
 
-    /// Creator's token account (for optional initial buy)
+    /// Creator-lock vault - holds the initial buy until graduation
     #[account(
-        init_if_needed,
+        init,
+        payer = creator,
+        seeds = [CREATOR_LOCK_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve,
+    )]
+    pub creator_lock_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Metaplex metadata PDA, created via CPI
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub metadata: AccountInfo<'info>,
+
+    /// Creator fee split (required only when `fee_recipients` is set)
+    #[account(
+        init,
         payer = creator,
-        associated_token::mint = mint,
-        associated_token::authority = creator,
+        seeds = [FEE_SPLIT_SEED, mint.key().as_ref()],
+        bump,
+        space = 8 + CreatorFeeSplit::INIT_SPACE
     )]
-    pub creator_token_account: Account<'info, TokenAccount>,
+    pub fee_split: Option<Account<'info, CreatorFeeSplit>>,
 
     /// Treasury wallet for protocol fees
     /// CHECK: Validated by program logic
@@ -67,8 +92,11 @@ pub struct CreateToken<'info> {
 
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub metadata_program: AccountInfo<'info>,
 }
 
 /// Arguments for creating a new token
@@ -78,6 +106,31 @@ pub struct CreateTokenArgs {
     pub symbol: String,
     pub uri: String,
     pub initial_buy_lamports: Option<u64>, // Optional: Creator can buy on launch
+    pub options: LaunchOptions,
+}
+
+/// Optional launch settings chosen by the creator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct LaunchOptions {
+    /// Length of the opening batch auction in seconds (None = trading opens immediately)
+    pub opening_auction_duration: Option<i64>,
+    /// Max tokens a single account may hold while on the curve, in bps of supply (None = no limit)
+    pub max_wallet_bps: Option<u16>,
+    /// Linear vesting of the locked dev buy after graduation, in seconds (None = released at once)
+    pub dev_vesting_duration: Option<i64>,
+    /// Only allow transfers through the curve until graduation (Token-2022 transfer hook)
+    pub curve_only_transfers: bool,
+    /// Wallets sharing the creator fee (empty = all to the creator)
+    pub fee_recipients: Vec<FeeShare>,
+    /// Seconds until an ungraduated curve turns sell-only (None = never expires)
+    pub expiry_duration: Option<i64>,
+}
+
+/// One wallet's share of the creator fee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FeeShare {
+    pub wallet: Pubkey,
+    pub share_bps: u16,
 }
 
 pub fn handler(
@@ -86,13 +139,14 @@ pub fn handler(
     symbol: String,
     uri: String,
     initial_buy_lamports: Option<u64>,
+    options: LaunchOptions,
 ) -> Result<()> {
     // =====================
     // VALIDATION
     // =====================
-    require!(name.len() <= MAX_NAME_LENGTH, FuseError::NameTooLong);
-    require!(symbol.len() <= MAX_SYMBOL_LENGTH, FuseError::SymbolTooLong);
-    require!(uri.len() <= MAX_URI_LENGTH, FuseError::UriTooLong);
+    validate_launch(&name, &symbol, &uri, &options, ctx.accounts.fee_split.is_some())?;
+    // Legacy SPL mints can't carry a transfer hook
+    require!(!options.curve_only_transfers, FuseError::CurveOnlyRequiresToken2022);
 
     let clock = Clock::get()?;
     let curve = &mut ctx.accounts.curve;
@@ -101,17 +155,21 @@ pub fn handler(
     // =====================
     // INITIALIZE CURVE STATE
     // =====================
-    curve.creator = ctx.accounts.creator.key();
-    curve.token_mint = ctx.accounts.mint.key();
-    curve.token_total_supply = TOTAL_SUPPLY;
-    curve.virtual_sol_reserves = VIRTUAL_SOL_RESERVES;
-    curve.virtual_token_reserves = VIRTUAL_TOKEN_RESERVES;
-    curve.real_sol_reserves = 0;
-    curve.real_token_reserves = REAL_TOKEN_RESERVES;
-    curve.complete = false;
-    curve.bump = bump;
-    curve.creator_fee_accumulated = 0;
-    curve.launch_timestamp = clock.unix_timestamp;
+    init_curve(
+        curve,
+        ctx.accounts.creator.key(),
+        ctx.accounts.mint.key(),
+        ctx.accounts.token_program.key(),
+        bump,
+        &options,
+        clock.unix_timestamp,
+    );
+    init_fee_split(
+        &mut ctx.accounts.fee_split,
+        ctx.accounts.mint.key(),
+        &options,
+        ctx.bumps.fee_split,
+    )?;
     curve.name = name.clone();
     curve.symbol = symbol.clone();
     curve.uri = uri.clone();
@@ -134,6 +192,28 @@ pub fn handler(
     );
     token::mint_to(mint_ctx, TOTAL_SUPPLY)?;
 
+    // =====================
+    // CREATE METAPLEX METADATA
+    // =====================
+    // The curve PDA is update authority so the creator can fix metadata
+    // through the program until graduation, when it becomes immutable
+    metadata_interface::create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: curve.to_account_info(),
+                payer: ctx.accounts.creator.to_account_info(),
+                update_authority: curve.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        DataV2::new(name.clone(), symbol.clone(), uri.clone()),
+        true,
+    )?;
+
     // =====================
     // CHARGE CREATION FEE
     // =====================
@@ -151,28 +231,11 @@ pub fn handler(
     msg!("Created: {} ({}) Mint: {}", name, symbol, ctx.accounts.mint.key());
 
     // =====================
-    // OPTIONAL: INITIAL BUY
+    // OPTIONAL: INITIAL BUY (LOCKED)
     // =====================
     if let Some(lamports) = initial_buy_lamports {
         if lamports > 0 {
-            // Calculate tokens out using bonding curve formula
-            let tokens_out = calculate_tokens_out(
-                curve.virtual_sol_reserves,
-                curve.virtual_token_reserves,
-                lamports,
-            )?;
-
-            // Update reserves
-            curve.virtual_sol_reserves = curve.virtual_sol_reserves
-                .checked_add(lamports)
-                .ok_or(FuseError::MathOverflow)?;
-            curve.virtual_token_reserves = curve.virtual_token_reserves
-                .checked_sub(tokens_out)
-                .ok_or(FuseError::MathOverflow)?;
-            curve.real_sol_reserves = lamports;
-            curve.real_token_reserves = curve.real_token_reserves
-                .checked_sub(tokens_out)
-                .ok_or(FuseError::MathOverflow)?;
+            let tokens_out = apply_dev_buy(curve, lamports)?;
 
             // Transfer SOL from creator to curve
             anchor_lang::system_program::transfer(
@@ -186,19 +249,19 @@ pub fn handler(
                 lamports,
             )?;
 
-            // Transfer tokens to creator
+            // Transfer tokens to the creator-lock vault
             let transfer_ctx = CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 token::Transfer {
                     from: ctx.accounts.vault.to_account_info(),
-                    to: ctx.accounts.creator_token_account.to_account_info(),
+                    to: ctx.accounts.creator_lock_vault.to_account_info(),
                     authority: curve.to_account_info(),
                 },
                 signer_seeds,
             );
             token::transfer(transfer_ctx, tokens_out)?;
 
-            msg!("Init Buy: {} l → {} t", lamports, tokens_out);
+            msg!("Init Buy (locked): {} l → {} t", lamports, tokens_out);
         }
     }
 
@@ -217,9 +280,163 @@ pub fn handler(
     Ok(())
 }
 
+/// Checks the launch parameters shared by both token programs
+pub(crate) fn validate_launch(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    options: &LaunchOptions,
+    has_fee_split_account: bool,
+) -> Result<()> {
+    require!(name.len() <= MAX_NAME_LENGTH, FuseError::NameTooLong);
+    require!(symbol.len() <= MAX_SYMBOL_LENGTH, FuseError::SymbolTooLong);
+    require!(uri.len() <= MAX_URI_LENGTH, FuseError::UriTooLong);
+    if let Some(duration) = options.opening_auction_duration {
+        require!(
+            duration > 0 && duration <= MAX_OPENING_AUCTION_DURATION,
+            FuseError::InvalidAuctionDuration
+        );
+    }
+    if let Some(bps) = options.max_wallet_bps {
+        require!(bps > 0 && bps <= 10_000, FuseError::InvalidMaxWallet);
+    }
+    if !options.fee_recipients.is_empty() {
+        let recipients = &options.fee_recipients;
+        let total_bps = recipients.iter().map(|r| r.share_bps as u64).sum::<u64>();
+        require!(
+            recipients.len() <= MAX_FEE_RECIPIENTS
+                && total_bps == 10_000
+                && recipients.iter().all(|r| r.share_bps > 0)
+                && recipients.iter().enumerate().all(|(i, r)| {
+                    recipients[..i].iter().all(|other| other.wallet != r.wallet)
+                }),
+            FuseError::InvalidFeeSplit
+        );
+        require!(has_fee_split_account, FuseError::FeeSplitMismatch);
+    }
+    if let Some(duration) = options.dev_vesting_duration {
+        require!(
+            duration > 0 && duration <= MAX_DEV_VESTING_DURATION,
+            FuseError::InvalidVestingDuration
+        );
+    }
+    if let Some(duration) = options.expiry_duration {
+        require!(
+            (MIN_CURVE_LIFETIME..=MAX_CURVE_LIFETIME).contains(&duration),
+            FuseError::InvalidExpiry
+        );
+    }
+
+    Ok(())
+}
+
+/// Sets a fresh curve's reserves and launch settings
+pub(crate) fn init_curve(
+    curve: &mut BondingCurve,
+    creator: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+    bump: u8,
+    options: &LaunchOptions,
+    now: i64,
+) {
+    curve.creator = creator;
+    curve.pending_creator = None;
+    curve.token_mint = mint;
+    curve.token_program = token_program;
+    curve.token_total_supply = TOTAL_SUPPLY;
+    curve.virtual_sol_reserves = VIRTUAL_SOL_RESERVES;
+    curve.virtual_token_reserves = VIRTUAL_TOKEN_RESERVES;
+    curve.real_sol_reserves = 0;
+    curve.real_token_reserves = REAL_TOKEN_RESERVES;
+    curve.complete = false;
+    curve.bump = bump;
+    curve.creator_fee_accumulated = 0;
+    curve.launch_timestamp = now;
+    curve.opening_auction_end = options
+        .opening_auction_duration
+        .map_or(0, |duration| now + duration);
+    curve.opening_settled = false;
+    curve.max_wallet_bps = options.max_wallet_bps.unwrap_or(0);
+    curve.creator_locked_tokens = 0;
+    curve.creator_released_tokens = 0;
+    curve.creator_vesting_duration = options.dev_vesting_duration.unwrap_or(0);
+    curve.graduated_at = 0;
+    curve.traded = false;
+    curve.has_referral_boost = false;
+    curve.expires_at = options
+        .expiry_duration
+        .map_or(0, |duration| now + duration);
+    curve.wind_down_at = 0;
+    curve.curve_only_transfers = options.curve_only_transfers;
+    curve.has_fee_split = !options.fee_recipients.is_empty();
+    curve.metadata_locked = false;
+}
+
+/// Records the fee recipients when the launch splits the creator fee
+pub(crate) fn init_fee_split(
+    fee_split: &mut Option<Account<CreatorFeeSplit>>,
+    mint: Pubkey,
+    options: &LaunchOptions,
+    bump: Option<u8>,
+) -> Result<()> {
+    if options.fee_recipients.is_empty() {
+        return Ok(());
+    }
+
+    let fee_split = fee_split.as_mut().ok_or(FuseError::FeeSplitMismatch)?;
+    fee_split.mint = mint;
+    fee_split.recipients = options
+        .fee_recipients
+        .iter()
+        .map(|r| FeeRecipient {
+            wallet: r.wallet,
+            share_bps: r.share_bps,
+            claimed: 0,
+        })
+        .collect();
+    fee_split.total_received = 0;
+    fee_split.bump = bump.ok_or(FuseError::FeeSplitMismatch)?;
+
+    Ok(())
+}
+
+/// Books the creator's initial buy on the curve and returns the tokens bought
+pub(crate) fn apply_dev_buy(curve: &mut BondingCurve, lamports: u64) -> Result<u64> {
+    // Calculate tokens out using bonding curve formula
+    let tokens_out = calculate_tokens_out(
+        curve.virtual_sol_reserves,
+        curve.virtual_token_reserves,
+        lamports,
+    )?;
+
+    // Cap the dev buy's share of supply
+    let max_dev_tokens = (TOTAL_SUPPLY as u128)
+        .checked_mul(MAX_DEV_BUY_BPS as u128)
+        .ok_or(FuseError::MathOverflow)?
+        .checked_div(10000)
+        .ok_or(FuseError::MathOverflow)? as u64;
+    require!(tokens_out <= max_dev_tokens, FuseError::DevBuyTooLarge);
+
+    // Update reserves
+    curve.virtual_sol_reserves = curve.virtual_sol_reserves
+        .checked_add(lamports)
+        .ok_or(FuseError::MathOverflow)?;
+    curve.virtual_token_reserves = curve.virtual_token_reserves
+        .checked_sub(tokens_out)
+        .ok_or(FuseError::MathOverflow)?;
+    curve.real_sol_reserves = lamports;
+    curve.real_token_reserves = curve.real_token_reserves
+        .checked_sub(tokens_out)
+        .ok_or(FuseError::MathOverflow)?;
+    curve.creator_locked_tokens = tokens_out;
+
+    Ok(tokens_out)
+}
+
 /// Calculate tokens out using constant product formula
 /// tokens_out = (virtual_token_reserves * sol_in) / (virtual_sol_reserves + sol_in)
-fn calculate_tokens_out(
+pub(crate) fn calculate_tokens_out(
     virtual_sol_reserves: u64,
     virtual_token_reserves: u64,
     sol_in: u64,
@@ -238,3 +455,27 @@ fn calculate_tokens_out(
     
     Ok(tokens_out)
 }
+
+/// Net SOL needed to buy exactly `tokens_out`, rounded up
+pub(crate) fn calculate_sol_in(
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+    tokens_out: u64,
+) -> Result<u64> {
+    let numerator = (virtual_sol_reserves as u128)
+        .checked_mul(tokens_out as u128)
+        .ok_or(FuseError::MathOverflow)?;
+
+    let denominator = (virtual_token_reserves as u128)
+        .checked_sub(tokens_out as u128)
+        .filter(|d| *d > 0)
+        .ok_or(FuseError::MathOverflow)?;
+
+    let sol_in = numerator
+        .checked_add(denominator - 1)
+        .ok_or(FuseError::MathOverflow)?
+        .checked_div(denominator)
+        .ok_or(FuseError::MathOverflow)?;
+
+    u64::try_from(sol_in).map_err(|_| FuseError::MathOverflow.into())
+}
@@ -0,0 +1,361 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{
+    self, InitializeAccount3, InitializeMint2, MetadataPointerInitialize, MintTo,
+    TokenMetadataInitialize, TransferHookInitialize,
+};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_interface::spl_token_2022::state::{Account as TokenAccountState, Mint as MintState};
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use crate::state::{BondingCurve, CreatorFeeSplit};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::CurveInitialized;
+use crate::instructions::create_token::{
+    apply_dev_buy, init_curve, init_fee_split, validate_launch, LaunchOptions,
+};
+
+/// CreateToken2022 - Launches a token as a Token-2022 mint
+///
+/// Same curve as `create_token`, but the metadata lives on the mint itself
+/// (MetadataPointer + TokenMetadata extensions) instead of a Metaplex account.
+///
+/// With `curve_only_transfers`, the mint also gets a TransferHook pointing at
+/// the fuse-hook program. Its `initialize_extra_account_meta_list` must run
+/// (e.g. as the next instruction in the same transaction) before any trade.
+///
+/// The mint, vault and creator-lock vault are created in the handler, since
+/// their sizes depend on which extensions the launch uses.
+#[derive(Accounts)]
+pub struct CreateToken2022<'info> {
+    /// The creator launching the token (pays for account creation)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The bonding curve PDA - holds all state for this token
+    #[account(
+        init,
+        payer = creator,
+        seeds = [SEED, mint.key().as_ref()],
+        bump,
+        space = 8 + BondingCurve::INIT_SPACE
+    )]
+    pub curve: Account<'info, BondingCurve>,
+
+    /// The new token mint (fresh keypair, created by this instruction)
+    #[account(mut)]
+    pub mint: Signer<'info>,
+
+    /// CHECK: Vault PDA that holds the supply, created by this instruction
+    #[account(
+        mut,
+        seeds = [b"vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: Creator-lock vault PDA, created by this instruction
+    #[account(
+        mut,
+        seeds = [CREATOR_LOCK_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub creator_lock_vault: UncheckedAccount<'info>,
+
+    /// Creator fee split (required only when `fee_recipients` is set)
+    #[account(
+        init,
+        payer = creator,
+        seeds = [FEE_SPLIT_SEED, mint.key().as_ref()],
+        bump,
+        space = 8 + CreatorFeeSplit::INIT_SPACE
+    )]
+    pub fee_split: Option<Account<'info, CreatorFeeSplit>>,
+
+    /// Treasury wallet for protocol fees
+    /// CHECK: Validated by program logic
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(
+    ctx: Context<CreateToken2022>,
+    name: String,
+    symbol: String,
+    uri: String,
+    initial_buy_lamports: Option<u64>,
+    options: LaunchOptions,
+) -> Result<()> {
+    // =====================
+    // VALIDATION
+    // =====================
+    validate_launch(&name, &symbol, &uri, &options, ctx.accounts.fee_split.is_some())?;
+
+    let clock = Clock::get()?;
+    let curve_key = ctx.accounts.curve.key();
+    let mint_key = ctx.accounts.mint.key();
+    let bump = ctx.bumps.curve;
+
+    // =====================
+    // INITIALIZE CURVE STATE
+    // =====================
+    let curve = &mut ctx.accounts.curve;
+    init_curve(
+        curve,
+        ctx.accounts.creator.key(),
+        mint_key,
+        ctx.accounts.token_program.key(),
+        bump,
+        &options,
+        clock.unix_timestamp,
+    );
+    curve.name = name.clone();
+    curve.symbol = symbol.clone();
+    curve.uri = uri.clone();
+    init_fee_split(
+        &mut ctx.accounts.fee_split,
+        mint_key,
+        &options,
+        ctx.bumps.fee_split,
+    )?;
+
+    let seeds = &[SEED, mint_key.as_ref(), &[bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    // =====================
+    // CREATE MINT WITH EXTENSIONS
+    // =====================
+    let mut extensions = vec![ExtensionType::MetadataPointer];
+    if options.curve_only_transfers {
+        extensions.push(ExtensionType::TransferHook);
+    }
+    let mint_space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+
+    // Token-2022 grows the mint for the metadata but doesn't fund it
+    let metadata_space = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(curve_key))?,
+        mint: mint_key,
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    }
+    .tlv_size_of()?;
+
+    system_program::create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        Rent::get()?.minimum_balance(mint_space + metadata_space),
+        mint_space as u64,
+        &ctx.accounts.token_program.key(),
+    )?;
+
+    token_interface::metadata_pointer_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        None,
+        Some(mint_key),
+    )?;
+
+    // The curve keeps the hook authority so `migrate` can remove it
+    if options.curve_only_transfers {
+        token_interface::transfer_hook_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferHookInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            Some(curve_key),
+            Some(TRANSFER_HOOK_PROGRAM_ID),
+        )?;
+    }
+
+    // No freeze authority is ever set; mint authority is revoked at graduation
+    token_interface::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        TOKEN_DECIMALS,
+        &curve_key,
+        None,
+    )?;
+
+    // =====================
+    // WRITE TOKEN METADATA
+    // =====================
+    // The curve PDA is update authority so the creator can fix metadata
+    // through the program until graduation, when it becomes immutable
+    token_interface::token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: ctx.accounts.mint.to_account_info(),
+                update_authority: ctx.accounts.curve.to_account_info(),
+                mint_authority: ctx.accounts.curve.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        name.clone(),
+        symbol.clone(),
+        uri.clone(),
+    )?;
+
+    // =====================
+    // CREATE VAULTS
+    // =====================
+    let account_space = ExtensionType::try_calculate_account_len::<TokenAccountState>(
+        &ExtensionType::get_required_init_account_extensions(&extensions),
+    )?;
+    create_curve_token_account(
+        ctx.accounts,
+        &ctx.accounts.vault,
+        &[b"vault", mint_key.as_ref(), &[ctx.bumps.vault]],
+        account_space,
+    )?;
+    create_curve_token_account(
+        ctx.accounts,
+        &ctx.accounts.creator_lock_vault,
+        &[CREATOR_LOCK_SEED, mint_key.as_ref(), &[ctx.bumps.creator_lock_vault]],
+        account_space,
+    )?;
+
+    // =====================
+    // OPTIONAL: INITIAL BUY (LOCKED)
+    // =====================
+    let dev_lamports = initial_buy_lamports.unwrap_or(0);
+    let dev_tokens = if dev_lamports > 0 {
+        apply_dev_buy(&mut ctx.accounts.curve, dev_lamports)?
+    } else {
+        0
+    };
+
+    // =====================
+    // MINT SUPPLY TO VAULTS
+    // =====================
+    // The dev buy is minted straight into the creator-lock vault: no token
+    // transfer happens before the transfer hook's account list exists
+    let vault_supply = TOTAL_SUPPLY
+        .checked_sub(dev_tokens)
+        .ok_or(FuseError::MathOverflow)?;
+    for (vault, amount) in [
+        (&ctx.accounts.vault, vault_supply),
+        (&ctx.accounts.creator_lock_vault, dev_tokens),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: vault.to_account_info(),
+                    authority: ctx.accounts.curve.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
+
+    // =====================
+    // CHARGE CREATION FEE
+    // =====================
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        CREATION_FEE_LAMPORTS,
+    )?;
+
+    msg!("Created (Token-2022): {} ({}) Mint: {}", name, symbol, mint_key);
+
+    if dev_tokens > 0 {
+        // Transfer SOL from creator to curve
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.curve.to_account_info(),
+                },
+            ),
+            dev_lamports,
+        )?;
+
+        msg!("Init Buy (locked): {} l → {} t", dev_lamports, dev_tokens);
+    }
+
+    // =====================
+    // EMIT EVENT
+    // =====================
+    emit!(CurveInitialized {
+        mint: mint_key,
+        creator: ctx.accounts.creator.key(),
+        name,
+        symbol,
+        uri,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Creates a curve-owned Token-2022 account at one of the curve's vault PDAs
+fn create_curve_token_account<'info>(
+    accounts: &CreateToken2022<'info>,
+    account: &UncheckedAccount<'info>,
+    account_seeds: &[&[u8]],
+    space: usize,
+) -> Result<()> {
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: accounts.creator.to_account_info(),
+                to: account.to_account_info(),
+            },
+            &[account_seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &accounts.token_program.key(),
+    )?;
+
+    token_interface::initialize_account3(CpiContext::new(
+        accounts.token_program.to_account_info(),
+        InitializeAccount3 {
+            account: account.to_account_info(),
+            mint: accounts.mint.to_account_info(),
+            authority: accounts.curve.to_account_info(),
+        },
+    ))?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::BondingCurve;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::CurveExpired;
+
+/// ExpireCurve - Moves a failed launch into sell-only wind-down
+///
+/// Permissionless once `expires_at` has passed. Buys are already rejected
+/// from that moment; this records when wind-down began, which starts the
+/// grace period before the creator may call `close_expired_curve`.
+#[derive(Accounts)]
+pub struct ExpireCurve<'info> {
+    /// Anyone can expire a curve past its deadline
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = !curve_config.complete @ FuseError::CurveAlreadyMigrated,
+        constraint = curve_config.wind_down_at == 0 @ FuseError::AlreadyWindingDown,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+pub fn handler(ctx: Context<ExpireCurve>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    let clock = Clock::get()?;
+
+    require!(curve_config.is_expired(clock.unix_timestamp), FuseError::CurveNotExpired);
+    // A curve that made it to the threshold graduates instead
+    require!(
+        curve_config.real_sol_reserves < GRADUATION_SOL_THRESHOLD,
+        FuseError::GraduationReached
+    );
+
+    curve_config.wind_down_at = clock.unix_timestamp;
+
+    emit!(CurveExpired {
+        mint: ctx.accounts.mint.key(),
+        expires_at: curve_config.expires_at,
+        real_sol_reserves: curve_config.real_sol_reserves,
+        real_token_reserves: curve_config.real_token_reserves,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Curve expired, winding down: {}", ctx.accounts.mint.key());
+
+    Ok(())
+}
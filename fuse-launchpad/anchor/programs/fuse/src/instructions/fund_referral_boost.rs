@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer as SolTransfer};
+use anchor_spl::token_interface::Mint;
+use crate::state::{BondingCurve, ReferralBoost, ReferralVault};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::ReferralBoostFunded;
+use crate::instructions::claim_creator_fees::withdrawable_creator_fees;
+
+/// FundReferralBoost - Creator tops up a bonus budget for referrers
+///
+/// While the budget lasts, referred `buy`/`sell` trades of this mint pay
+/// the direct referrer an extra `bonus_bps` of the trade volume on top of
+/// the protocol's referral fee. Funded with the creator's own SOL or,
+/// when there is no fee split, from withdrawable creator fees. Each call
+/// may also change the bonus rate. Only referrers bound on the trader's
+/// profile earn the bonus, and `close_referral_boost` returns what is
+/// left once the curve stops trading.
+#[derive(Accounts)]
+pub struct FundReferralBoost<'info> {
+    #[account(
+        mut,
+        address = curve_config.creator @ FuseError::Unauthorized,
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = !curve_config.complete @ FuseError::TradingDisabled,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [REFERRAL_BOOST_SEED, mint.key().as_ref()],
+        bump,
+        space = 8 + ReferralBoost::INIT_SPACE
+    )]
+    pub referral_boost: Account<'info, ReferralBoost>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundReferralBoost>, amount: u64, bonus_bps: u16, from_creator_fees: bool) -> Result<()> {
+    require!(
+        amount > 0 && bonus_bps > 0 && bonus_bps <= MAX_REFERRAL_BOOST_BPS,
+        FuseError::InvalidReferralBoost
+    );
+
+    let curve_config = &mut ctx.accounts.curve_config;
+
+    // =====================
+    // MOVE FUNDS INTO THE BOOST
+    // =====================
+    if from_creator_fees {
+        require!(!curve_config.has_fee_split, FuseError::FeeSplitFeesLocked);
+        require!(withdrawable_creator_fees(curve_config)? >= amount, FuseError::InsufficientCreatorFees);
+
+        curve_config.creator_fee_accumulated = curve_config.creator_fee_accumulated
+            .checked_sub(amount)
+            .ok_or(FuseError::MathOverflow)?;
+        **curve_config.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.referral_boost.to_account_info().try_borrow_mut_lamports()? += amount;
+    } else {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                SolTransfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.referral_boost.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    // Closing the curve then requires closing the boost too
+    curve_config.has_referral_boost = true;
+
+    let referral_boost = &mut ctx.accounts.referral_boost;
+    referral_boost.mint = ctx.accounts.mint.key();
+    referral_boost.bonus_bps = bonus_bps;
+    referral_boost.budget_remaining = referral_boost.budget_remaining
+        .checked_add(amount)
+        .ok_or(FuseError::MathOverflow)?;
+    referral_boost.bump = ctx.bumps.referral_boost;
+
+    emit!(ReferralBoostFunded {
+        mint: referral_boost.mint,
+        amount,
+        from_creator_fees,
+        bonus_bps,
+        budget_remaining: referral_boost.budget_remaining,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Referral boost funded: {} (remaining {})", amount, referral_boost.budget_remaining);
+
+    Ok(())
+}
+
+/// Pays the boost bonus for `volume` into a referrer's vault, capped by
+/// the remaining budget. Returns the amount paid.
+pub(crate) fn pay_referral_boost(
+    referral_boost: &mut Account<ReferralBoost>,
+    referrer_vault: &Account<ReferralVault>,
+    volume: u64,
+) -> Result<u64> {
+    let bonus = (volume as u128)
+        .checked_mul(referral_boost.bonus_bps as u128)
+        .ok_or(FuseError::MathOverflow)?
+        .checked_div(10_000)
+        .ok_or(FuseError::MathOverflow)? as u64;
+    let bonus = bonus.min(referral_boost.budget_remaining);
+
+    if bonus > 0 {
+        **referral_boost.to_account_info().try_borrow_mut_lamports()? -= bonus;
+        **referrer_vault.to_account_info().try_borrow_mut_lamports()? += bonus;
+
+        referral_boost.budget_remaining -= bonus;
+        referral_boost.total_paid = referral_boost.total_paid
+            .checked_add(bonus)
+            .ok_or(FuseError::MathOverflow)?;
+    }
+
+    Ok(bonus)
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{ReferralVault, UserProfile};
+use crate::constants::*;
+
+/// InitReferralVault - Creates the referral vault for an existing profile
+///
+/// `register_user` creates the vault alongside new profiles; profiles
+/// registered before vaults existed call this once to start earning.
+#[derive(Accounts)]
+pub struct InitReferralVault<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump = user_profile.bump,
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    #[account(
+        init,
+        payer = user,
+        seeds = [REFERRAL_VAULT_SEED, user.key().as_ref()],
+        bump,
+        space = 8 + ReferralVault::INIT_SPACE
+    )]
+    pub referral_vault: Account<'info, ReferralVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitReferralVault>) -> Result<()> {
+    let referral_vault = &mut ctx.accounts.referral_vault;
+    referral_vault.authority = ctx.accounts.user.key();
+    referral_vault.bump = ctx.bumps.referral_vault;
+
+    msg!("Referral vault created for {}", ctx.accounts.user_profile.username);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::BondingCurve;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::MetadataUpdated;
+
+/// LockMetadata - Permanently disables `update_token_metadata`
+///
+/// One-way: lets a creator prove to holders that the name, symbol and URI
+/// won't change, without waiting for graduation.
+#[derive(Accounts)]
+pub struct LockMetadata<'info> {
+    #[account(address = curve_config.creator @ FuseError::Unauthorized)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = !curve_config.metadata_locked @ FuseError::MetadataLocked,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+pub fn handler(ctx: Context<LockMetadata>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    curve_config.metadata_locked = true;
+
+    emit!(MetadataUpdated {
+        mint: ctx.accounts.mint.key(),
+        uri: curve_config.uri.clone(),
+        locked: true,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
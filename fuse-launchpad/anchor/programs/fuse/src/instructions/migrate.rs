@@ -1,20 +1,43 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{
+    self, Mint, TokenAccount, TokenInterface, SetAuthority, TransferHookUpdate,
+    TokenMetadataUpdateAuthority,
+};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_2022::instruction::AuthorityType;
 use anchor_spl::associated_token::AssociatedToken;
-use crate::state::BondingCurve;
+use crate::state::{BondingCurve, CreatorFeeSplit, PoolFees};
 use crate::constants::*;
 use crate::errors::FuseError;
 use crate::events::CurveCompleted;
-use crate::meteora_interface::{self, dynamic_amm, dynamic_vault, mpl_token_metadata, CustomizableParams};
+use crate::instructions::claim_creator_fees::sweep_creator_fees;
+use crate::metadata_interface::{self, UpdateMetadataAccountsV2};
+use crate::transfer_interface::{self, TransferChecked};
+use crate::program::FuseLaunchpad;
+use crate::meteora_interface::{self, dynamic_amm, dynamic_vault, mpl_token_metadata, CustomizableParams, LOCK_ESCROW_SEED};
 
 /// Migrate - Graduates the token from bonding curve to Meteora Dynamic AMM
 /// 
 /// This instruction:
 /// 1. Validates graduation threshold is reached
-/// 2. Pays out accumulated creator fees
-/// 3. Creates a Meteora Dynamic AMM pool with liquidity
-/// 4. Locks liquidity in escrow (protocol earns trading fees)
-/// 5. Marks curve as complete
+/// 2. Pays out accumulated creator fees (or moves them into the fee split)
+/// 3. Hands the liquidity to the migration authority for the Meteora
+///    Dynamic AMM pool
+/// 4. Records the `PoolFees` PDA and the lock escrow it will own, so
+///    creator and protocol can later collect trading fees via
+///    `claim_pool_fees`
+/// 5. Revokes the mint authority so supply is fixed forever
+///    and makes the token metadata immutable
+/// 6. Marks curve as complete
+///
+/// Pool creation and the LP lock happen off-chain: the migration bot
+/// creates the pool, then `create_lock_escrow` with `pool_fees` as owner
+/// and `lock`s the whole LP into it. Fees can only be claimed once that
+/// is done, and only from the escrow recorded here.
+///
+/// Works for both SPL Token and Token-2022 launches. A curve-only mint's
+/// transfer hook is removed first, so the DEX sees a plain token.
 #[derive(Accounts)]
 pub struct Migrate<'info> {
     /// Migration authority (could be protocol admin or automated keeper)
@@ -31,7 +54,7 @@ pub struct Migrate<'info> {
 
     /// The token mint being graduated
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     /// Token vault holding remaining supply
     #[account(
@@ -41,12 +64,36 @@ pub struct Migrate<'info> {
         token::mint = mint,
         token::authority = curve_config,
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: The creator who receives the accumulated fees. Must match curve_config.creator.
     #[account(mut, address = curve_config.creator @ FuseError::Unauthorized)]
     pub creator: AccountInfo<'info>,
 
+    /// Creator fee split - receives the creator payout when the curve has one
+    #[account(
+        mut,
+        seeds = [FEE_SPLIT_SEED, mint.key().as_ref()],
+        bump = fee_split.bump,
+    )]
+    pub fee_split: Option<Account<'info, CreatorFeeSplit>>,
+
+    /// Owner of the lock escrow, records how LP fees are shared
+    #[account(
+        init,
+        payer = migration_authority,
+        seeds = [POOL_FEES_SEED, mint.key().as_ref()],
+        bump,
+        space = 8 + PoolFees::INIT_SPACE
+    )]
+    pub pool_fees: Account<'info, PoolFees>,
+
+    /// The upgrade authority receives the protocol share of LP fees
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, FuseLaunchpad>,
+
+    pub program_data: Account<'info, ProgramData>,
+
     // ====================================================
     // METEORA DYNAMIC AMM ACCOUNTS
     // ====================================================
@@ -95,15 +142,15 @@ pub struct Migrate<'info> {
 
     /// Migration authority's token A account (receives tokens from vault)
     #[account(mut)]
-    pub payer_token_a: Account<'info, TokenAccount>,
+    pub payer_token_a: InterfaceAccount<'info, TokenAccount>,
 
     /// Migration authority's WSOL account (receives SOL converted to WSOL)
     #[account(mut)]
-    pub payer_token_b: Account<'info, TokenAccount>,
+    pub payer_token_b: InterfaceAccount<'info, TokenAccount>,
 
     /// Migration authority's LP token account
     #[account(mut)]
-    pub payer_pool_lp: Account<'info, TokenAccount>,
+    pub payer_pool_lp: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: Protocol fee token A account
     #[account(mut)]
@@ -113,12 +160,28 @@ pub struct Migrate<'info> {
     #[account(mut)]
     pub protocol_token_b_fee: AccountInfo<'info>,
 
+    /// CHECK: Metaplex metadata PDA of the graduating token
+    /// (SPL Token launches only; Token-2022 metadata lives on the mint)
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub token_metadata: Option<UncheckedAccount<'info>>,
+
     /// CHECK: LP mint metadata PDA for Metaplex
     #[account(mut)]
     pub mint_metadata: AccountInfo<'info>,
 
-    /// CHECK: Lock escrow PDA for protocol fee claiming
-    #[account(mut)]
+    /// CHECK: Meteora lock escrow of pool_fees for the new pool, created
+    /// off-chain after migration; only its derivation is checked here
+    #[account(
+        mut,
+        seeds = [LOCK_ESCROW_SEED, meteora_pool.key().as_ref(), pool_fees.key().as_ref()],
+        bump,
+        seeds::program = dynamic_amm::ID,
+    )]
     pub lock_escrow: AccountInfo<'info>,
 
     /// CHECK: Escrow vault for locked LP
@@ -128,7 +191,8 @@ pub struct Migrate<'info> {
     // ====================================================
     // PROGRAMS
     // ====================================================
-    pub token_program: Program<'info, Token>,
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -166,7 +230,12 @@ pub fn handler(ctx: Context<Migrate>) -> Result<()> {
     // 1. PAY CREATOR FEES
     // =====================
     let creator_fees = curve_config.creator_fee_accumulated;
-    if creator_fees > 0 {
+    if curve_config.has_fee_split {
+        // Recipients claim their shares from the split afterwards
+        let fee_split = ctx.accounts.fee_split.as_mut().ok_or(FuseError::FeeSplitMismatch)?;
+        let swept = sweep_creator_fees(curve_config, fee_split)?;
+        msg!("Creator Fees Split: {}", swept);
+    } else if creator_fees > 0 {
         **curve_config.to_account_info().try_borrow_mut_lamports()? -= creator_fees;
         **ctx.accounts.creator.try_borrow_mut_lamports()? += creator_fees;
         
@@ -187,17 +256,35 @@ pub fn handler(ctx: Context<Migrate>) -> Result<()> {
     // =====================
     // 3. TRANSFER TOKENS FROM VAULT TO MIGRATION AUTHORITY
     // =====================
+    // Curve-only mode ends here: drop the transfer hook before any token
+    // leaves the curve so pools and wallets never need the hook accounts
+    if curve_config.curve_only_transfers {
+        token_interface::transfer_hook_update(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferHookUpdate {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: curve_config.to_account_info(),
+                },
+                signer,
+            ),
+            None,
+        )?;
+    }
+
     if tokens_for_liquidity > 0 {
         let transfer_ctx = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            Transfer {
+            TransferChecked {
                 from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.payer_token_a.to_account_info(),
                 authority: curve_config.to_account_info(),
             },
             signer,
         );
-        token::transfer(transfer_ctx, tokens_for_liquidity)?;
+        transfer_interface::transfer_checked(transfer_ctx, tokens_for_liquidity, ctx.accounts.mint.decimals)?;
     }
 
     // =====================
@@ -271,14 +358,89 @@ pub fn handler(ctx: Context<Migrate>) -> Result<()> {
     */
 
     // =====================
-    // 6. MARK CURVE COMPLETE
+    // 6. REVOKE MINT (AND ANY LEGACY FREEZE) AUTHORITY, LOCK METADATA
+    // =====================
+    let mut revoked = Vec::new();
+    if ctx.accounts.mint.mint_authority.is_some() {
+        revoked.push(AuthorityType::MintTokens);
+    }
+    if ctx.accounts.mint.freeze_authority.is_some() {
+        revoked.push(AuthorityType::FreezeAccount);
+    }
+    if curve_config.curve_only_transfers {
+        revoked.push(AuthorityType::TransferHookProgramId);
+    }
+    for authority_type in revoked {
+        token_interface::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: curve_config.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                signer,
+            ),
+            authority_type,
+            None,
+        )?;
+    }
+    ctx.accounts.mint.reload()?;
+
+    // Metadata can no longer be changed once the token trades on a DEX
+    if curve_config.token_program == Token2022::id() {
+        token_interface::token_metadata_update_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateAuthority {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.mint.to_account_info(),
+                    current_authority: curve_config.to_account_info(),
+                    new_authority: curve_config.to_account_info(),
+                },
+                signer,
+            ),
+            OptionalNonZeroPubkey::default(),
+        )?;
+    } else {
+        let token_metadata = ctx.accounts.token_metadata.as_ref().ok_or(FuseError::MetadataAccountMissing)?;
+        metadata_interface::update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                ctx.accounts.metadata_program.to_account_info(),
+                UpdateMetadataAccountsV2 {
+                    metadata: token_metadata.to_account_info(),
+                    update_authority: curve_config.to_account_info(),
+                },
+                signer,
+            ),
+            None,
+            None,
+            Some(false),
+        )?;
+    }
+
+    // =====================
+    // 7. MARK CURVE COMPLETE
     // =====================
     curve_config.complete = true;
+    curve_config.graduated_at = clock.unix_timestamp;
     curve_config.real_sol_reserves = 0;
     curve_config.real_token_reserves = 0;
 
+    let pool_fees = &mut ctx.accounts.pool_fees;
+    pool_fees.mint = mint_key;
+    pool_fees.pool = ctx.accounts.meteora_pool.key();
+    pool_fees.lock_escrow = ctx.accounts.lock_escrow.key();
+    pool_fees.creator = curve_config.creator;
+    pool_fees.protocol_authority = ctx.accounts.program_data
+        .upgrade_authority_address
+        .ok_or(FuseError::Unauthorized)?;
+    pool_fees.creator_share_bps = POOL_FEE_CREATOR_SHARE_BPS;
+    pool_fees.total_token_claimed = 0;
+    pool_fees.total_sol_claimed = 0;
+    pool_fees.bump = ctx.bumps.pool_fees;
+
     // =====================
-    // 7. EMIT GRADUATION EVENT
+    // 8. EMIT GRADUATION EVENT
     // =====================
     let final_market_cap = calculate_market_cap(
         curve_config.virtual_sol_reserves,
@@ -291,6 +453,8 @@ pub fn handler(ctx: Context<Migrate>) -> Result<()> {
         final_market_cap,
         total_sol_raised: sol_for_liquidity,
         creator_payout: creator_fees,
+        mint_authority: ctx.accounts.mint.mint_authority.into(),
+        freeze_authority: ctx.accounts.mint.freeze_authority.into(),
         timestamp: clock.unix_timestamp,
     });
 
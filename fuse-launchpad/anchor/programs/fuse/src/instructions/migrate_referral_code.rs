@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::UsernameChanged;
+use crate::instructions::register_user::normalize_username;
+
+/// MigrateReferralCode - Moves a pre-normalization username code to its lowercase seed
+///
+/// Codes registered before usernames were normalized are seeded by the
+/// mixed-case name, which `set_referrer` no longer derives. Anyone can
+/// move such a code (grown by `realloc_account` first) to the seed of its
+/// normalized name: owner and counters are kept, the owner's profile is
+/// renamed to match, and the payer funds the new account and gets the
+/// old one's rent. Run it for every legacy code right after the upgrade,
+/// before a new user can register the lowercase name. Names that don't
+/// normalize, or whose lowercase name is taken, can only be replaced by
+/// the owner via `change_username`.
+#[derive(Accounts)]
+pub struct MigrateReferralCode<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, legacy_code.code.as_bytes()],
+        bump = legacy_code.bump,
+        constraint = normalize_username(&legacy_code.code)? != legacy_code.code @ FuseError::CodeAlreadyNormalized,
+        close = payer,
+    )]
+    pub legacy_code: Account<'info, ReferralCode>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [REFERRAL_SEED, normalize_username(&legacy_code.code)?.as_bytes()],
+        bump,
+        space = 8 + ReferralCode::INIT_SPACE
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, legacy_code.owner.as_ref()],
+        bump = owner_profile.bump,
+    )]
+    pub owner_profile: Account<'info, UserProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateReferralCode>) -> Result<()> {
+    let legacy_code = &ctx.accounts.legacy_code;
+    let username = normalize_username(&legacy_code.code)?;
+
+    let referral_code = &mut ctx.accounts.referral_code;
+    referral_code.owner = legacy_code.owner;
+    referral_code.code = username.clone();
+    referral_code.campaign_id = legacy_code.campaign_id;
+    referral_code.pending_owner = legacy_code.pending_owner;
+    referral_code.referral_count = legacy_code.referral_count;
+    referral_code.total_fees = legacy_code.total_fees;
+    referral_code.bump = ctx.bumps.referral_code;
+
+    // The profile keeps pointing at its username code
+    let owner_profile = &mut ctx.accounts.owner_profile;
+    if owner_profile.username == legacy_code.code {
+        let old_username = std::mem::replace(&mut owner_profile.username, username);
+
+        emit!(UsernameChanged {
+            user: legacy_code.owner,
+            old_username,
+            new_username: owner_profile.username.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    msg!("Referral code migrated: {} → {}", legacy_code.code, referral_code.code);
+
+    Ok(())
+}
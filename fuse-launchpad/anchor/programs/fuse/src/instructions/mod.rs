@@ -1,15 +1,75 @@
 pub mod initialize;
 pub mod create_token;
+pub mod create_token_2022;
 pub mod buy;
 pub mod sell;
 pub mod migrate;
 pub mod register_user;
 pub mod set_referrer;
+pub mod commit_opening;
+pub mod settle_opening;
+pub mod claim_opening;
+pub mod realloc_account;
+pub mod claim_creator_tokens;
+pub mod claim_creator_fees;
+pub mod claim_fee_share;
+pub mod propose_creator_transfer;
+pub mod accept_creator_transfer;
+pub mod update_token_metadata;
+pub mod lock_metadata;
+pub mod expire_curve;
+pub mod close_expired_curve;
+pub mod cancel_launch;
+pub mod close_graduated_curve;
+pub mod claim_pool_fees;
+pub mod set_referral_tiers;
+pub mod claim_referral_fees;
+pub mod change_username;
+pub mod create_referral_code;
+pub mod close_user_profile;
+pub mod fund_referral_boost;
+pub mod set_referral_binding_duration;
+pub mod transfer_referral_code;
+pub mod accept_referral_code;
+pub mod close_referral_boost;
+pub mod init_referral_vault;
+pub mod refund_creator_lock;
+pub mod migrate_referral_code;
 
 pub use initialize::*;
 pub use create_token::*;
+pub use create_token_2022::*;
 pub use buy::*;
 pub use sell::*;
 pub use migrate::*;
 pub use register_user::*;
 pub use set_referrer::*;
+pub use commit_opening::*;
+pub use settle_opening::*;
+pub use claim_opening::*;
+pub use realloc_account::*;
+pub use claim_creator_tokens::*;
+pub use claim_creator_fees::*;
+pub use claim_fee_share::*;
+pub use propose_creator_transfer::*;
+pub use accept_creator_transfer::*;
+pub use update_token_metadata::*;
+pub use lock_metadata::*;
+pub use expire_curve::*;
+pub use close_expired_curve::*;
+pub use cancel_launch::*;
+pub use close_graduated_curve::*;
+pub use claim_pool_fees::*;
+pub use set_referral_tiers::*;
+pub use claim_referral_fees::*;
+pub use change_username::*;
+pub use create_referral_code::*;
+pub use close_user_profile::*;
+pub use fund_referral_boost::*;
+pub use set_referral_binding_duration::*;
+pub use transfer_referral_code::*;
+pub use accept_referral_code::*;
+pub use close_referral_boost::*;
+pub use init_referral_vault::*;
+pub use refund_creator_lock::*;
+pub use migrate_referral_code::*;
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::BondingCurve;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::CreatorTransferProposed;
+
+/// ProposeCreatorTransfer - Nominates a new wallet for the creator role
+///
+/// The role (creator fees and the creator-lock vault) only moves once the
+/// nominee calls `accept_creator_transfer`. Proposing `None` cancels.
+#[derive(Accounts)]
+pub struct ProposeCreatorTransfer<'info> {
+    #[account(address = curve_config.creator @ FuseError::Unauthorized)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+pub fn handler(ctx: Context<ProposeCreatorTransfer>, new_creator: Option<Pubkey>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    curve_config.pending_creator = new_creator;
+
+    emit!(CreatorTransferProposed {
+        mint: ctx.accounts.mint.key(),
+        creator: ctx.accounts.creator.key(),
+        pending_creator: new_creator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
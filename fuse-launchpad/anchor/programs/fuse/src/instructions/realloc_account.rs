@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer as SolTransfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token::Token;
+use crate::state::{BondingCurve, ReferralCode, UserProfile};
+use crate::errors::FuseError;
+
+/// ReallocAccount - Grows a pre-upgrade account to the current layout
+///
+/// `BondingCurve`, `UserProfile` and `ReferralCode` only ever gain fields
+/// at the end, so an account created before an upgrade is resized in
+/// place and the new fields read as zero. Legacy defaults that zero
+/// would get wrong are filled in: curves are SPL Token and already
+/// traded, and existing referrer bindings start their expiry clock now.
+/// Anyone can pay for the resize.
+#[derive(Accounts)]
+pub struct ReallocAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Checked in the handler (program-owned, known discriminator)
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReallocAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+
+    let new_len = {
+        let data = account.try_borrow_data()?;
+        if data.starts_with(BondingCurve::DISCRIMINATOR) {
+            8 + BondingCurve::INIT_SPACE
+        } else if data.starts_with(UserProfile::DISCRIMINATOR) {
+            8 + UserProfile::INIT_SPACE
+        } else if data.starts_with(ReferralCode::DISCRIMINATOR) {
+            8 + ReferralCode::INIT_SPACE
+        } else {
+            return err!(FuseError::AccountNotReallocatable);
+        }
+    };
+    let old_len = account.data_len();
+    require!(old_len < new_len, FuseError::AccountAlreadyCurrent);
+
+    // =====================
+    // TOP UP RENT AND RESIZE
+    // =====================
+    let rent_needed = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if rent_needed > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                SolTransfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            rent_needed,
+        )?;
+    }
+    account.resize(new_len)?;
+
+    // =====================
+    // LEGACY DEFAULTS
+    // =====================
+    let now = Clock::get()?.unix_timestamp;
+    let mut data = account.try_borrow_mut_data()?;
+    if data.starts_with(BondingCurve::DISCRIMINATOR) {
+        let mut curve = BondingCurve::try_deserialize(&mut &data[..])?;
+        curve.token_program = Token::id();
+        curve.traded = true;
+        curve.try_serialize(&mut &mut data[..])?;
+    } else if data.starts_with(UserProfile::DISCRIMINATOR) {
+        let mut profile = UserProfile::try_deserialize(&mut &data[..])?;
+        if profile.referrer.is_some() {
+            profile.referrer_bound_at = now;
+        }
+        profile.try_serialize(&mut &mut data[..])?;
+    }
+
+    msg!("Account reallocated: {} → {} bytes", old_len, new_len);
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::BondingCurve;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::CreatorLockRefunded;
+use crate::transfer_interface::{self, TransferChecked};
+
+/// RefundCreatorLock - Sells the creator's locked dev buy back to a winding-down curve
+///
+/// Locked tokens only unlock at graduation, which an expired curve never
+/// reaches. During wind-down the creator can instead sell the whole
+/// locked amount back at the curve price, paying the regular 1% fee,
+/// like any holder's `sell`.
+///
+/// Curve-only Token-2022 launches also need the transfer hook accounts
+/// in `remaining_accounts`.
+#[derive(Accounts)]
+pub struct RefundCreatorLock<'info> {
+    #[account(
+        mut,
+        address = curve_config.creator @ FuseError::Unauthorized,
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = curve_config.wind_down_at > 0 @ FuseError::CurveNotExpired,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_LOCK_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub creator_lock_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Protocol treasury, receives the fee on the refund
+    #[account(mut, address = TREASURY @ FuseError::InvalidTreasury)]
+    pub treasury: AccountInfo<'info>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, RefundCreatorLock<'info>>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    let amount_in = curve_config.creator_locked_tokens
+        .checked_sub(curve_config.creator_released_tokens)
+        .ok_or(FuseError::MathOverflow)?;
+    require!(amount_in > 0, FuseError::NothingToClaim);
+
+    // =====================
+    // BONDING CURVE MATH (Inverse)
+    // =====================
+    let sol_out = (curve_config.virtual_sol_reserves as u128)
+        .checked_mul(amount_in as u128)
+        .ok_or(FuseError::MathOverflow)?
+        .checked_div(
+            (curve_config.virtual_token_reserves as u128)
+                .checked_add(amount_in as u128)
+                .ok_or(FuseError::MathOverflow)?
+        )
+        .ok_or(FuseError::MathOverflow)? as u64;
+    let sol_out = sol_out.min(curve_config.real_sol_reserves);
+
+    // =====================
+    // CALCULATE FEES (1%)
+    // =====================
+    let total_fee = sol_out
+        .checked_mul(FEE_BASIS_POINTS)
+        .ok_or(FuseError::MathOverflow)?
+        .checked_div(10000)
+        .ok_or(FuseError::MathOverflow)?;
+
+    let protocol_fee = total_fee
+        .checked_mul(PROTOCOL_FEE_SHARE)
+        .ok_or(FuseError::MathOverflow)?
+        .checked_div(100)
+        .ok_or(FuseError::MathOverflow)?;
+
+    let creator_fee = total_fee
+        .checked_sub(protocol_fee)
+        .ok_or(FuseError::MathOverflow)?;
+
+    let creator_receives = sol_out
+        .checked_sub(total_fee)
+        .ok_or(FuseError::MathOverflow)?;
+
+    // =====================
+    // UPDATE RESERVES
+    // =====================
+    curve_config.virtual_sol_reserves = curve_config.virtual_sol_reserves
+        .checked_sub(sol_out)
+        .ok_or(FuseError::MathOverflow)?;
+    curve_config.virtual_token_reserves = curve_config.virtual_token_reserves
+        .checked_add(amount_in)
+        .ok_or(FuseError::MathOverflow)?;
+    curve_config.real_sol_reserves = curve_config.real_sol_reserves
+        .checked_sub(sol_out)
+        .ok_or(FuseError::MathOverflow)?;
+    curve_config.real_token_reserves = curve_config.real_token_reserves
+        .checked_add(amount_in)
+        .ok_or(FuseError::MathOverflow)?;
+
+    curve_config.creator_fee_accumulated = curve_config.creator_fee_accumulated
+        .checked_add(creator_fee)
+        .ok_or(FuseError::MathOverflow)?;
+    curve_config.creator_released_tokens = curve_config.creator_locked_tokens;
+
+    // =====================
+    // MOVE LOCKED TOKENS INTO THE CURVE VAULT
+    // =====================
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[SEED, mint_key.as_ref(), &[curve_config.bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_context_token = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.creator_lock_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: curve_config.to_account_info(),
+        },
+        signer,
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    transfer_interface::transfer_checked(cpi_context_token, amount_in, ctx.accounts.mint.decimals)?;
+
+    // =====================
+    // PAY CREATOR AND TREASURY
+    // =====================
+    **curve_config.to_account_info().try_borrow_mut_lamports()? -= creator_receives;
+    **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? += creator_receives;
+
+    if protocol_fee > 0 {
+        **curve_config.to_account_info().try_borrow_mut_lamports()? -= protocol_fee;
+        **ctx.accounts.treasury.try_borrow_mut_lamports()? += protocol_fee;
+    }
+
+    emit!(CreatorLockRefunded {
+        mint: mint_key,
+        creator: ctx.accounts.creator.key(),
+        token_amount: amount_in,
+        sol_amount: creator_receives,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Creator lock refunded: {} t → {} l", amount_in, creator_receives);
+
+    Ok(())
+}
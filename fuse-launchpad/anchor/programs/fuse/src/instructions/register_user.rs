@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::FuseError;
+use crate::events::UserRegistered;
 
 #[derive(Accounts)]
 #[instruction(username: String)]
@@ -21,30 +22,122 @@ pub struct RegisterUser<'info> {
     #[account(
         init,
         payer = user,
-        seeds = [REFERRAL_SEED, username.as_bytes()],
+        seeds = [REFERRAL_SEED, normalize_username(&username)?.as_bytes()],
         bump,
         space = 8 + ReferralCode::INIT_SPACE
     )]
     pub referral_code_mapping: Account<'info, ReferralCode>,
 
+    #[account(
+        init,
+        payer = user,
+        seeds = [REFERRAL_VAULT_SEED, user.key().as_ref()],
+        bump,
+        space = 8 + ReferralVault::INIT_SPACE
+    )]
+    pub referral_vault: Account<'info, ReferralVault>,
+
+    /// Left by `close_user_profile`; read and closed again in the same
+    /// call (a wallet that never closed a profile gets an empty one)
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [CLOSED_PROFILE_SEED, user.key().as_ref()],
+        bump,
+        space = 8 + ClosedProfile::INIT_SPACE
+    )]
+    pub closed_profile: Account<'info, ClosedProfile>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<RegisterUser>, username: String) -> Result<()> {
-    require!(username.len() <= 20, FuseError::NameTooLong); // Reuse error or make new one
+    let username = normalize_username(&username)?;
 
+    // A wallet that closed its profile gets its referrer binding back
+    let closed_profile = &ctx.accounts.closed_profile;
     let user_profile = &mut ctx.accounts.user_profile;
     user_profile.authority = ctx.accounts.user.key();
     user_profile.username = username.clone();
-    user_profile.referrer = None;
+    user_profile.referrer = closed_profile.referrer;
+    user_profile.referred_via = closed_profile.referred_via;
+    user_profile.referrer_bound_at = closed_profile.referrer_bound_at;
     user_profile.referral_count = 0;
+    user_profile.referred_volume = 0;
     user_profile.total_referral_fees = 0;
+    user_profile.unclaimed_referral_fees = 0;
+    user_profile.claimed_referral_fees = 0;
     user_profile.bump = ctx.bumps.user_profile;
 
+    ctx.accounts.closed_profile.close(ctx.accounts.user.to_account_info())?;
+
     let referral_code = &mut ctx.accounts.referral_code_mapping;
     referral_code.owner = ctx.accounts.user.key();
     referral_code.code = username;
+    referral_code.campaign_id = 0;
+    referral_code.pending_owner = None;
+    referral_code.referral_count = 0;
+    referral_code.total_fees = 0;
     referral_code.bump = ctx.bumps.referral_code_mapping;
 
+    let referral_vault = &mut ctx.accounts.referral_vault;
+    referral_vault.authority = ctx.accounts.user.key();
+    referral_vault.bump = ctx.bumps.referral_vault;
+
+    emit!(UserRegistered {
+        user: ctx.accounts.user.key(),
+        username: ctx.accounts.user_profile.username.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
+
+/// Validates a username and returns its lowercase form, which is both the
+/// stored name and the ReferralCode seed, so "Alice" and "alice" collide
+pub(crate) fn normalize_username(username: &str) -> Result<String> {
+    require!(username.len() >= MIN_USERNAME_LENGTH, FuseError::UsernameTooShort);
+    require!(username.len() <= MAX_USERNAME_LENGTH, FuseError::UsernameTooLong);
+    require!(
+        username.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_'),
+        FuseError::InvalidUsernameCharacter
+    );
+
+    let normalized = username.to_ascii_lowercase();
+    require!(!RESERVED_USERNAMES.contains(&normalized.as_str()), FuseError::UsernameReserved);
+
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn username_length_bounds() {
+        assert_eq!(normalize_username("ab").unwrap_err(), FuseError::UsernameTooShort.into());
+        assert_eq!(normalize_username("abc").unwrap(), "abc");
+        assert_eq!(normalize_username(&"a".repeat(20)).unwrap(), "a".repeat(20));
+        assert_eq!(normalize_username(&"a".repeat(21)).unwrap_err(), FuseError::UsernameTooLong.into());
+    }
+
+    #[test]
+    fn username_is_lowercased() {
+        assert_eq!(normalize_username("Alice_99").unwrap(), "alice_99");
+    }
+
+    #[test]
+    fn username_rejects_other_characters() {
+        for username in ["al-ice", "al ice", "alicé", "al.ice"] {
+            assert_eq!(normalize_username(username).unwrap_err(), FuseError::InvalidUsernameCharacter.into());
+        }
+    }
+
+    #[test]
+    fn username_rejects_reserved_names_in_any_case() {
+        for username in RESERVED_USERNAMES {
+            assert_eq!(normalize_username(username).unwrap_err(), FuseError::UsernameReserved.into());
+        }
+        assert_eq!(normalize_username("Admin").unwrap_err(), FuseError::UsernameReserved.into());
+    }
+}
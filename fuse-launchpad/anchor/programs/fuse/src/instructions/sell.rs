@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
-use crate::state::{BondingCurve, UserProfile};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{BondingCurve, ReferralBoost, ReferralCode, ReferralConfig, ReferralVault, UserProfile};
 use crate::constants::*;
 use crate::errors::FuseError;
 use crate::events::TradeEvent;
+use crate::referral::{distribute_referral_fee, FeeSource, ReferralAccounts};
+use crate::transfer_interface::{self, TransferChecked};
 
+/// Sell - Swaps tokens back to SOL on the curve
+///
+/// Curve-only Token-2022 launches also need the transfer hook accounts
+/// in `remaining_accounts`.
 #[derive(Accounts)]
 pub struct Sell<'info> {
     #[account(mut)]
@@ -15,10 +21,11 @@ pub struct Sell<'info> {
         seeds = [SEED, mint.key().as_ref()],
         bump = curve_config.bump,
         constraint = !curve_config.complete @ FuseError::TradingDisabled,
+        constraint = curve_config.opening_auction_end == 0 || curve_config.opening_settled @ FuseError::OpeningAuctionActive,
     )]
     pub curve_config: Account<'info, BondingCurve>,
 
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
@@ -27,14 +34,14 @@ pub struct Sell<'info> {
         token::mint = mint,
         token::authority = curve_config,
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         token::mint = mint,
         token::authority = user,
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: Treasury wallet - validated by protocol
     #[account(mut)]
@@ -48,18 +55,70 @@ pub struct Sell<'info> {
     )]
     pub user_profile: Option<Account<'info, UserProfile>>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [USER_SEED, referrer_profile.authority.as_ref()],
+        bump = referrer_profile.bump,
+    )]
     pub referrer_profile: Option<Account<'info, UserProfile>>,
 
-    /// CHECK: Referrer wallet
-    #[account(mut)]
-    pub referrer_wallet: Option<AccountInfo<'info>>,
+    /// Referrer's vault, accrues the referral fee
+    #[account(
+        mut,
+        seeds = [REFERRAL_VAULT_SEED, referrer_vault.authority.as_ref()],
+        bump = referrer_vault.bump,
+    )]
+    pub referrer_vault: Option<Account<'info, ReferralVault>>,
+
+    /// Second tier: the referrer's own referrer
+    #[account(
+        mut,
+        seeds = [USER_SEED, second_referrer_profile.authority.as_ref()],
+        bump = second_referrer_profile.bump,
+    )]
+    pub second_referrer_profile: Option<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_VAULT_SEED, second_referrer_vault.authority.as_ref()],
+        bump = second_referrer_vault.bump,
+    )]
+    pub second_referrer_vault: Option<Account<'info, ReferralVault>>,
 
-    pub token_program: Program<'info, Token>,
+    /// CHECK: Tiered referral rates and binding expiry; defaults apply
+    /// until the config is created (see `ReferralConfig::load_or_default`)
+    #[account(seeds = [REFERRAL_CONFIG_SEED], bump)]
+    pub referral_config: UncheckedAccount<'info>,
+
+    /// Code the user was (or is being) referred through, for per-code stats
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, referral_code_account.code.as_bytes()],
+        bump = referral_code_account.bump,
+    )]
+    pub referral_code_account: Option<Account<'info, ReferralCode>>,
+
+    /// Creator-funded bonus for referrers of this mint
+    #[account(
+        mut,
+        seeds = [REFERRAL_BOOST_SEED, mint.key().as_ref()],
+        bump = referral_boost.bump,
+    )]
+    pub referral_boost: Option<Account<'info, ReferralBoost>>,
+
+    /// Code the referrer was referred through, to find the second tier
+    #[account(
+        seeds = [REFERRAL_SEED, second_referral_code_account.code.as_bytes()],
+        bump = second_referral_code_account.bump,
+    )]
+    pub second_referral_code_account: Option<Account<'info, ReferralCode>>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Sell>, amount_in: u64, min_sol_out: u64) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, Sell<'info>>, amount_in: u64, min_sol_out: u64) -> Result<()> {
     let curve_config = &mut ctx.accounts.curve_config;
     let clock = Clock::get()?;
 
@@ -137,13 +196,15 @@ pub fn handler(ctx: Context<Sell>, amount_in: u64, min_sol_out: u64) -> Result<(
     // =====================
     let cpi_context_token = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.vault.to_account_info(),
             authority: ctx.accounts.user.to_account_info(),
         },
-    );
-    token::transfer(cpi_context_token, amount_in)?;
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+    transfer_interface::transfer_checked(cpi_context_token, amount_in, ctx.accounts.mint.decimals)?;
 
     // Prepare PDA signer
     let mint_key = ctx.accounts.mint.key();
@@ -159,33 +220,26 @@ pub fn handler(ctx: Context<Sell>, amount_in: u64, min_sol_out: u64) -> Result<(
     // =====================
     // TRANSFER PROTOCOL FEE TO TREASURY (AND REFERRER)
     // =====================
-    let mut final_protocol_fee = protocol_fee;
-    let mut referral_fee = 0;
-
-    // Check for referral
-    if let Some(user_profile) = &ctx.accounts.user_profile {
-        if let Some(referrer_key) = user_profile.referrer {
-            // Validate referrer accounts are passed correctly
-            if let Some(referrer_profile) = &mut ctx.accounts.referrer_profile {
-                if let Some(referrer_wallet) = &ctx.accounts.referrer_wallet {
-                    if referrer_profile.authority == referrer_key && referrer_wallet.key() == referrer_key {
-                        // Calculate 10% of protocol fee
-                        referral_fee = protocol_fee / 10;
-                        final_protocol_fee = protocol_fee.checked_sub(referral_fee).ok_or(FuseError::MathOverflow)?;
-
-                        // Transfer to referrer (from curve PDA)
-                        **curve_config.to_account_info().try_borrow_mut_lamports()? -= referral_fee;
-                        **referrer_wallet.try_borrow_mut_lamports()? += referral_fee;
-                        
-                        // Update referrer stats
-                        referrer_profile.total_referral_fees = referrer_profile.total_referral_fees.checked_add(referral_fee).unwrap_or(referrer_profile.total_referral_fees);
-
-                        msg!("Ref fee paid: {} to {}", referral_fee, referrer_key);
-                    }
-                }
-            }
-        }
-    }
+    let final_protocol_fee = distribute_referral_fee(
+        ReferralAccounts {
+            user_profile: &mut ctx.accounts.user_profile,
+            referrer_profile: &mut ctx.accounts.referrer_profile,
+            referrer_vault: &ctx.accounts.referrer_vault,
+            second_referrer_profile: &mut ctx.accounts.second_referrer_profile,
+            second_referrer_vault: &ctx.accounts.second_referrer_vault,
+            second_referral_code_account: &ctx.accounts.second_referral_code_account,
+            referral_config: &ReferralConfig::load_or_default(&ctx.accounts.referral_config)?,
+            referral_code_account: &mut ctx.accounts.referral_code_account,
+            referral_boost: &mut ctx.accounts.referral_boost,
+        },
+        FeeSource::Curve(&curve_config.to_account_info()),
+        ctx.accounts.mint.key(),
+        ctx.accounts.user.key(),
+        protocol_fee,
+        sol_out,
+        false,
+        clock.unix_timestamp,
+    )?;
 
     if final_protocol_fee > 0 {
         **curve_config.to_account_info().try_borrow_mut_lamports()? -= final_protocol_fee;
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::ReferralConfig;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::program::FuseLaunchpad;
+
+/// SetReferralBindingDuration - Configures how long a referrer stays bound
+///
+/// Restricted to the program's upgrade authority. Once a binding is older
+/// than `duration`, `buy`/`sell` stop paying that referrer and the user may
+/// bind a new one. Zero keeps bindings forever.
+#[derive(Accounts)]
+pub struct SetReferralBindingDuration<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [REFERRAL_CONFIG_SEED],
+        bump,
+        space = 8 + ReferralConfig::INIT_SPACE
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, FuseLaunchpad>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ FuseError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetReferralBindingDuration>, duration: i64) -> Result<()> {
+    require!(
+        (0..=MAX_REFERRAL_BINDING_DURATION).contains(&duration),
+        FuseError::InvalidBindingDuration
+    );
+
+    let referral_config = &mut ctx.accounts.referral_config;
+    referral_config.binding_duration = duration;
+    referral_config.bump = ctx.bumps.referral_config;
+
+    msg!("Referral binding duration set: {}s", duration);
+
+    Ok(())
+}
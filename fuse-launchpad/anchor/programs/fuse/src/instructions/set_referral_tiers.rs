@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{ReferralConfig, ReferralTier};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::program::FuseLaunchpad;
+
+/// SetReferralTiers - Configures tiered referral rates
+///
+/// Restricted to the program's upgrade authority. Tiers are ascending:
+/// thresholds never decrease and each tier pays more. Referrers below the first tier
+/// earn the base `REFERRAL_FEE_SHARE`. An empty list restores the base
+/// rate for everyone.
+#[derive(Accounts)]
+pub struct SetReferralTiers<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [REFERRAL_CONFIG_SEED],
+        bump,
+        space = 8 + ReferralConfig::INIT_SPACE
+    )]
+    pub referral_config: Account<'info, ReferralConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, FuseLaunchpad>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ FuseError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetReferralTiers>, tiers: Vec<ReferralTier>) -> Result<()> {
+    // =====================
+    // VALIDATION
+    // =====================
+    require!(tiers.len() <= MAX_REFERRAL_TIERS, FuseError::InvalidReferralTiers);
+
+    let mut previous: Option<&ReferralTier> = None;
+    for tier in &tiers {
+        require!(
+            tier.fee_share >= REFERRAL_FEE_SHARE && tier.fee_share <= MAX_REFERRAL_FEE_SHARE,
+            FuseError::InvalidReferralTiers
+        );
+        if let Some(prev) = previous {
+            require!(
+                tier.min_referrals >= prev.min_referrals
+                    && tier.min_volume >= prev.min_volume
+                    && tier.fee_share > prev.fee_share,
+                FuseError::InvalidReferralTiers
+            );
+        }
+        previous = Some(tier);
+    }
+
+    let referral_config = &mut ctx.accounts.referral_config;
+    referral_config.tiers = tiers;
+    referral_config.bump = ctx.bumps.referral_config;
+
+    msg!("Referral tiers set: {}", referral_config.tiers.len());
+
+    Ok(())
+}
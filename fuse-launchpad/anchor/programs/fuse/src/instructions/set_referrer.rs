@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::constants::*;
 use crate::errors::FuseError;
+use crate::events::ReferrerBound;
 
 #[derive(Accounts)]
 #[instruction(referral_code: String)]
@@ -13,12 +14,12 @@ pub struct SetReferrer<'info> {
         mut,
         seeds = [USER_SEED, user.key().as_ref()],
         bump = user_profile.bump,
-        constraint = user_profile.referrer.is_none() @ FuseError::ReferralAlreadyExists
     )]
     pub user_profile: Account<'info, UserProfile>,
 
     #[account(
-        seeds = [REFERRAL_SEED, referral_code.as_bytes()],
+        mut,
+        seeds = [REFERRAL_SEED, referral_code.to_ascii_lowercase().as_bytes()],
         bump = referral_code_account.bump,
         constraint = referral_code_account.owner != user.key() @ FuseError::CannotReferSelf
     )]
@@ -31,16 +32,54 @@ pub struct SetReferrer<'info> {
         bump
     )]
     pub referrer_profile: Account<'info, UserProfile>,
+
+    /// CHECK: Needed to replace a referrer whose binding has expired;
+    /// defaults apply until the config is created
+    #[account(seeds = [REFERRAL_CONFIG_SEED], bump)]
+    pub referral_config: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<SetReferrer>, _referral_code: String) -> Result<()> {
+    let clock = Clock::get()?;
+    let referral_config = ReferralConfig::load_or_default(&ctx.accounts.referral_config)?;
     let user_profile = &mut ctx.accounts.user_profile;
     let referrer_key = ctx.accounts.referral_code_account.owner;
-    
+    let code_key = ctx.accounts.referral_code_account.key();
+
+    // A referrer can only be replaced once its binding has expired
+    require!(
+        user_profile.active_referrer(&referral_config, clock.unix_timestamp).is_none(),
+        FuseError::ReferralAlreadyExists
+    );
+
+    // Rebinding after expiry doesn't count the user again
+    let new_referrer = user_profile.referrer != Some(referrer_key);
+    let new_code = user_profile.referred_via != Some(code_key);
+
     user_profile.referrer = Some(referrer_key);
+    user_profile.referrer_bound_at = clock.unix_timestamp;
+    user_profile.referred_via = Some(code_key);
+
+    if new_code {
+        let referral_code_account = &mut ctx.accounts.referral_code_account;
+        referral_code_account.referral_count = referral_code_account.referral_count
+            .checked_add(1)
+            .ok_or(FuseError::MathOverflow)?;
+    }
+
+    if new_referrer {
+        let referrer_profile = &mut ctx.accounts.referrer_profile;
+        referrer_profile.referral_count = referrer_profile.referral_count
+            .checked_add(1)
+            .ok_or(FuseError::MathOverflow)?;
+    }
 
-    let referrer_profile = &mut ctx.accounts.referrer_profile;
-    referrer_profile.referral_count = referrer_profile.referral_count.checked_add(1).unwrap();
+    emit!(ReferrerBound {
+        user: ctx.accounts.user.key(),
+        referrer: referrer_key,
+        referral_code: Some(code_key),
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
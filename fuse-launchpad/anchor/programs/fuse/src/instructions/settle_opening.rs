@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{BondingCurve, OpeningAuction};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::{OpeningSettled, GraduationTriggered};
+use crate::instructions::buy::calculate_current_price;
+use crate::instructions::create_token::{calculate_sol_in, calculate_tokens_out};
+use crate::transfer_interface::{self, TransferChecked};
+
+/// SettleOpening - Clears the opening auction at one uniform curve price
+///
+/// This instruction:
+/// 1. Treats all committed SOL as a single buy against the curve
+/// 2. Charges the regular 1% fee on the combined amount
+/// 3. Moves the bought tokens into the auction vault for claiming
+/// 4. Opens the curve for regular trading
+///
+/// If the auction would buy more than the curve holds, only the SOL
+/// needed for the remaining supply is spent; the rest stays in escrow
+/// and is refunded pro-rata through `claim_opening`.
+///
+/// Curve-only Token-2022 launches also need the transfer hook accounts
+/// in `remaining_accounts`.
+#[derive(Accounts)]
+pub struct SettleOpening<'info> {
+    /// Anyone can settle once the commit window has ended
+    #[account(mut)]
+    pub settler: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = curve_config.opening_auction_end > 0 @ FuseError::NoOpeningAuction,
+        constraint = !curve_config.opening_settled @ FuseError::OpeningAlreadySettled,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Created here if nobody committed during the window
+    #[account(
+        init_if_needed,
+        payer = settler,
+        seeds = [AUCTION_SEED, mint.key().as_ref()],
+        bump,
+        space = 8 + OpeningAuction::INIT_SPACE
+    )]
+    pub opening_auction: Account<'info, OpeningAuction>,
+
+    /// Holds the auction's tokens until each bidder claims
+    #[account(
+        init,
+        payer = settler,
+        seeds = [AUCTION_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = curve_config,
+        token::token_program = token_program,
+    )]
+    pub auction_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Protocol treasury, receives the fee on the auction's SOL
+    #[account(mut, address = TREASURY @ FuseError::InvalidTreasury)]
+    pub treasury: AccountInfo<'info>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SettleOpening<'info>>) -> Result<()> {
+    let curve_config = &mut ctx.accounts.curve_config;
+    let opening_auction = &mut ctx.accounts.opening_auction;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= curve_config.opening_auction_end,
+        FuseError::OpeningAuctionNotEnded
+    );
+
+    opening_auction.mint = ctx.accounts.mint.key();
+    opening_auction.bump = ctx.bumps.opening_auction;
+
+    let total_committed = opening_auction.total_committed;
+    let mut net_amount = 0;
+    let mut tokens_out = 0;
+    let mut sol_used = 0;
+
+    if total_committed > 0 {
+        // =====================
+        // BONDING CURVE MATH (one uniform buy)
+        // =====================
+        sol_used = total_committed;
+        let uncapped_net = total_committed
+            .checked_sub(total_committed.checked_mul(FEE_BASIS_POINTS).ok_or(FuseError::MathOverflow)? / 10000)
+            .ok_or(FuseError::MathOverflow)?;
+        tokens_out = calculate_tokens_out(
+            curve_config.virtual_sol_reserves,
+            curve_config.virtual_token_reserves,
+            uncapped_net,
+        )?;
+
+        // A huge auction can't buy more than the curve holds: spend only
+        // what the remaining supply costs (fee included), refund the rest
+        if tokens_out > curve_config.real_token_reserves {
+            tokens_out = curve_config.real_token_reserves;
+            let net_needed = calculate_sol_in(
+                curve_config.virtual_sol_reserves,
+                curve_config.virtual_token_reserves,
+                tokens_out,
+            )?;
+            let fee_denominator = 10000 - FEE_BASIS_POINTS;
+            sol_used = ((net_needed as u128)
+                .checked_mul(10000)
+                .ok_or(FuseError::MathOverflow)?
+                .checked_add(fee_denominator as u128 - 1)
+                .ok_or(FuseError::MathOverflow)?
+                / fee_denominator as u128)
+                .min(total_committed as u128) as u64;
+        }
+
+        // =====================
+        // CALCULATE FEES (1%)
+        // =====================
+        let total_fee = sol_used
+            .checked_mul(FEE_BASIS_POINTS)
+            .ok_or(FuseError::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(FuseError::MathOverflow)?;
+
+        let protocol_fee = total_fee
+            .checked_mul(PROTOCOL_FEE_SHARE)
+            .ok_or(FuseError::MathOverflow)?
+            .checked_div(100)
+            .ok_or(FuseError::MathOverflow)?;
+
+        let creator_fee = total_fee
+            .checked_sub(protocol_fee)
+            .ok_or(FuseError::MathOverflow)?;
+
+        net_amount = sol_used
+            .checked_sub(total_fee)
+            .ok_or(FuseError::MathOverflow)?;
+
+        // =====================
+        // UPDATE RESERVES
+        // =====================
+        curve_config.virtual_sol_reserves = curve_config.virtual_sol_reserves
+            .checked_add(net_amount)
+            .ok_or(FuseError::MathOverflow)?;
+        curve_config.virtual_token_reserves = curve_config.virtual_token_reserves
+            .checked_sub(tokens_out)
+            .ok_or(FuseError::MathOverflow)?;
+        curve_config.real_sol_reserves = curve_config.real_sol_reserves
+            .checked_add(net_amount)
+            .ok_or(FuseError::MathOverflow)?;
+        curve_config.real_token_reserves = curve_config.real_token_reserves
+            .checked_sub(tokens_out)
+            .ok_or(FuseError::MathOverflow)?;
+
+        curve_config.creator_fee_accumulated = curve_config.creator_fee_accumulated
+            .checked_add(creator_fee)
+            .ok_or(FuseError::MathOverflow)?;
+
+        // =====================
+        // MOVE SOL OUT OF ESCROW (curve + treasury)
+        // =====================
+        // Any unspent SOL stays behind for bidder refunds
+        let amount_to_curve = net_amount.checked_add(creator_fee).ok_or(FuseError::MathOverflow)?;
+        **opening_auction.to_account_info().try_borrow_mut_lamports()? -= sol_used;
+        **curve_config.to_account_info().try_borrow_mut_lamports()? += amount_to_curve;
+        **ctx.accounts.treasury.try_borrow_mut_lamports()? += protocol_fee;
+
+        // =====================
+        // MOVE TOKENS TO AUCTION VAULT
+        // =====================
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[SEED, mint_key.as_ref(), &[curve_config.bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_context_token = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.auction_vault.to_account_info(),
+                authority: curve_config.to_account_info(),
+            },
+            signer,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+        transfer_interface::transfer_checked(cpi_context_token, tokens_out, ctx.accounts.mint.decimals)?;
+    }
+
+    // =====================
+    // OPEN TRADING
+    // =====================
+    opening_auction.tokens_allocated = tokens_out;
+    opening_auction.sol_refund = total_committed - sol_used;
+    opening_auction.settled = true;
+    curve_config.opening_settled = true;
+
+    let clearing_price = if tokens_out > 0 {
+        ((net_amount as u128) * 1_000_000 / (tokens_out as u128)) as u64
+    } else {
+        0
+    };
+
+    // =====================
+    // CHECK GRADUATION THRESHOLD
+    // =====================
+    if curve_config.real_sol_reserves >= GRADUATION_SOL_THRESHOLD {
+        let current_price = calculate_current_price(
+            curve_config.virtual_sol_reserves,
+            curve_config.virtual_token_reserves,
+        );
+        let market_cap = (TOTAL_SUPPLY as u128)
+            .checked_mul(current_price as u128)
+            .ok_or(FuseError::MathOverflow)?
+            .checked_div(1_000_000)
+            .ok_or(FuseError::MathOverflow)? as u64;
+
+        emit!(GraduationTriggered {
+            mint: ctx.accounts.mint.key(),
+            real_sol_reserves: curve_config.real_sol_reserves,
+            market_cap,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(OpeningSettled {
+        mint: ctx.accounts.mint.key(),
+        total_committed,
+        tokens_allocated: tokens_out,
+        sol_refund: opening_auction.sol_refund,
+        clearing_price,
+        virtual_sol_reserves: curve_config.virtual_sol_reserves,
+        virtual_token_reserves: curve_config.virtual_token_reserves,
+        real_sol_reserves: curve_config.real_sol_reserves,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Opening settled: {} l → {} t", total_committed, tokens_out);
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::ReferralCode;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::ReferralCodeTransferProposed;
+
+/// TransferReferralCode - Nominates a new owner for a campaign code
+///
+/// The code, its counters and the referees bound through it only move
+/// once the nominee calls `accept_referral_code`. Proposing `None`
+/// cancels. Username codes stay with their profile.
+#[derive(Accounts)]
+pub struct TransferReferralCode<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, referral_code.code.as_bytes()],
+        bump = referral_code.bump,
+        constraint = referral_code.owner == owner.key() @ FuseError::Unauthorized,
+        constraint = referral_code.campaign_id != 0 @ FuseError::UsernameCodeNotTransferable,
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+}
+
+pub fn handler(ctx: Context<TransferReferralCode>, new_owner: Option<Pubkey>) -> Result<()> {
+    let referral_code = &mut ctx.accounts.referral_code;
+    referral_code.pending_owner = new_owner;
+
+    emit!(ReferralCodeTransferProposed {
+        code: referral_code.code.clone(),
+        owner: ctx.accounts.owner.key(),
+        pending_owner: new_owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer as SolTransfer};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenMetadataUpdateField};
+use anchor_spl::token_interface::spl_token_metadata_interface::state::Field;
+use crate::state::BondingCurve;
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::MetadataUpdated;
+use crate::metadata_interface::{self, DataV2, UpdateMetadataAccountsV2};
+use crate::meteora_interface::mpl_token_metadata;
+
+/// UpdateTokenMetadata - Lets the creator fix the token's metadata URI
+///
+/// Only while the token is on the curve and the creator hasn't called
+/// `lock_metadata`. Updates the curve's copy and the on-chain metadata:
+/// the Metaplex account for SPL Token launches, the mint's TokenMetadata
+/// extension for Token-2022 launches.
+#[derive(Accounts)]
+pub struct UpdateTokenMetadata<'info> {
+    #[account(
+        mut,
+        address = curve_config.creator @ FuseError::Unauthorized,
+    )]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED, mint.key().as_ref()],
+        bump = curve_config.bump,
+        constraint = !curve_config.complete @ FuseError::CurveAlreadyMigrated,
+        constraint = !curve_config.metadata_locked @ FuseError::MetadataLocked,
+    )]
+    pub curve_config: Account<'info, BondingCurve>,
+
+    /// Mutable because Token-2022 stores the metadata on the mint
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Metaplex metadata PDA (SPL Token launches only)
+    #[account(
+        mut,
+        seeds = [METADATA_SEED, mpl_token_metadata::ID.as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub token_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metaplex Token Metadata program (SPL Token launches only)
+    #[account(address = mpl_token_metadata::ID)]
+    pub metadata_program: Option<UncheckedAccount<'info>>,
+
+    #[account(address = curve_config.token_program)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateTokenMetadata>, uri: String) -> Result<()> {
+    require!(uri.len() <= MAX_URI_LENGTH, FuseError::UriTooLong);
+
+    let curve_config = &mut ctx.accounts.curve_config;
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[SEED, mint_key.as_ref(), &[curve_config.bump]];
+    let signer = &[&seeds[..]];
+
+    if curve_config.token_program == Token2022::id() {
+        // =====================
+        // TOKEN-2022: UPDATE MINT EXTENSION
+        // =====================
+        // Token-2022 resizes the mint for a longer URI but doesn't fund it
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let new_len = (mint_info.data_len() + uri.len()).saturating_sub(curve_config.uri.len());
+        let top_up = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(mint_info.lamports());
+        if top_up > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    SolTransfer {
+                        from: ctx.accounts.creator.to_account_info(),
+                        to: mint_info.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+
+        token_interface::token_metadata_update_field(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint_info,
+                    update_authority: curve_config.to_account_info(),
+                },
+                signer,
+            ),
+            Field::Uri,
+            uri.clone(),
+        )?;
+    } else {
+        // =====================
+        // SPL TOKEN: UPDATE METAPLEX ACCOUNT
+        // =====================
+        let token_metadata = ctx.accounts.token_metadata.as_ref().ok_or(FuseError::MetadataAccountMissing)?;
+        let metadata_program = ctx.accounts.metadata_program.as_ref().ok_or(FuseError::MetadataAccountMissing)?;
+        metadata_interface::update_metadata_accounts_v2(
+            CpiContext::new_with_signer(
+                metadata_program.to_account_info(),
+                UpdateMetadataAccountsV2 {
+                    metadata: token_metadata.to_account_info(),
+                    update_authority: curve_config.to_account_info(),
+                },
+                signer,
+            ),
+            Some(DataV2::new(
+                curve_config.name.clone(),
+                curve_config.symbol.clone(),
+                uri.clone(),
+            )),
+            None,
+            None,
+        )?;
+    }
+
+    curve_config.uri = uri.clone();
+
+    emit!(MetadataUpdated {
+        mint: mint_key,
+        uri,
+        locked: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -7,6 +7,9 @@ pub mod constants;
 pub mod instructions;
 pub mod events;
 pub mod meteora_interface;
+pub mod metadata_interface;
+pub mod transfer_interface;
+pub mod referral;
 
 use state::*;
 use errors::*;
@@ -32,15 +35,33 @@ pub mod fuse_launchpad {
     /// * `name` - Token name (max 32 chars)
     /// * `symbol` - Token symbol/ticker (max 10 chars)
     /// * `uri` - Metadata URI (IPFS/Arweave, max 200 chars)
-    /// * `initial_buy_lamports` - Optional initial buy amount in lamports
+    /// * `initial_buy_lamports` - Optional initial buy amount in lamports (locked until graduation)
+    /// * `options` - Optional launch settings (opening auction, max wallet, dev vesting, fee split, ...)
     pub fn create_token(
         ctx: Context<CreateToken>,
         name: String,
         symbol: String,
         uri: String,
         initial_buy_lamports: Option<u64>,
+        options: LaunchOptions,
     ) -> Result<()> {
-        instructions::create_token::handler(ctx, name, symbol, uri, initial_buy_lamports)
+        instructions::create_token::handler(ctx, name, symbol, uri, initial_buy_lamports, options)
+    }
+
+    /// Create a new Token-2022 token with bonding curve
+    /// 
+    /// Metadata is stored on the mint (TokenMetadata extension) instead of Metaplex.
+    /// Takes the same arguments as `create_token`; `options.curve_only_transfers`
+    /// is only available here.
+    pub fn create_token_2022(
+        ctx: Context<CreateToken2022>,
+        name: String,
+        symbol: String,
+        uri: String,
+        initial_buy_lamports: Option<u64>,
+        options: LaunchOptions,
+    ) -> Result<()> {
+        instructions::create_token_2022::handler(ctx, name, symbol, uri, initial_buy_lamports, options)
     }
 
     /// Buy tokens from the bonding curve
@@ -48,7 +69,7 @@ pub mod fuse_launchpad {
     /// # Arguments
     /// * `amount_in` - SOL amount in lamports to spend
     /// * `min_tokens_out` - Minimum tokens expected (slippage protection)
-    pub fn buy(ctx: Context<Buy>, amount_in: u64, min_tokens_out: u64) -> Result<()> {
+    pub fn buy<'info>(ctx: Context<'_, '_, '_, 'info, Buy<'info>>, amount_in: u64, min_tokens_out: u64) -> Result<()> {
         instructions::buy::handler(ctx, amount_in, min_tokens_out)
     }
 
@@ -57,7 +78,7 @@ pub mod fuse_launchpad {
     /// # Arguments
     /// * `amount_in` - Token amount to sell
     /// * `min_sol_out` - Minimum SOL expected (slippage protection)
-    pub fn sell(ctx: Context<Sell>, amount_in: u64, min_sol_out: u64) -> Result<()> {
+    pub fn sell<'info>(ctx: Context<'_, '_, '_, 'info, Sell<'info>>, amount_in: u64, min_sol_out: u64) -> Result<()> {
         instructions::sell::handler(ctx, amount_in, min_sol_out)
     }
 
@@ -68,6 +89,188 @@ pub mod fuse_launchpad {
         instructions::migrate::handler(ctx)
     }
 
+    /// Commit SOL to a curve's opening auction
+    /// 
+    /// # Arguments
+    /// * `amount` - SOL amount in lamports to commit
+    pub fn commit_opening(ctx: Context<CommitOpening>, amount: u64) -> Result<()> {
+        instructions::commit_opening::handler(ctx, amount)
+    }
+
+    /// Clear all opening auction commitments at one uniform curve price
+    /// 
+    /// Permissionless, callable once the commit window has ended
+    pub fn settle_opening<'info>(ctx: Context<'_, '_, '_, 'info, SettleOpening<'info>>) -> Result<()> {
+        instructions::settle_opening::handler(ctx)
+    }
+
+    /// Claim the caller's pro-rata share of the opening auction tokens
+    pub fn claim_opening<'info>(ctx: Context<'_, '_, '_, 'info, ClaimOpening<'info>>) -> Result<()> {
+        instructions::claim_opening::handler(ctx)
+    }
+
+    /// Grow a curve, profile or referral code created before an upgrade
+    /// 
+    /// New fields read as zero, with legacy defaults filled in
+    pub fn realloc_account(ctx: Context<ReallocAccount>) -> Result<()> {
+        instructions::realloc_account::handler(ctx)
+    }
+
+    /// Release the creator's locked dev buy
+    /// 
+    /// Only callable after graduation, subject to optional linear vesting
+    pub fn claim_creator_tokens(ctx: Context<ClaimCreatorTokens>) -> Result<()> {
+        instructions::claim_creator_tokens::handler(ctx)
+    }
+
+    /// Withdraw the creator's accumulated trading fees
+    /// 
+    /// Callable by the curve creator at any time, before or after graduation.
+    /// Curves with a fee split pay every recipient (passed as remaining accounts).
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        instructions::claim_creator_fees::handler(ctx)
+    }
+
+    /// Claim one recipient's share of a split creator fee
+    pub fn claim_fee_share(ctx: Context<ClaimFeeShare>) -> Result<()> {
+        instructions::claim_fee_share::handler(ctx)
+    }
+
+    /// Nominate a new wallet for the creator role (None cancels)
+    pub fn propose_creator_transfer(ctx: Context<ProposeCreatorTransfer>, new_creator: Option<Pubkey>) -> Result<()> {
+        instructions::propose_creator_transfer::handler(ctx, new_creator)
+    }
+
+    /// Accept a pending creator transfer (moves fee and creator-lock rights)
+    pub fn accept_creator_transfer(ctx: Context<AcceptCreatorTransfer>) -> Result<()> {
+        instructions::accept_creator_transfer::handler(ctx)
+    }
+
+    /// Update the token's metadata URI (creator only, before graduation)
+    /// 
+    /// # Arguments
+    /// * `uri` - New metadata URI (IPFS/Arweave, max 200 chars)
+    pub fn update_token_metadata(ctx: Context<UpdateTokenMetadata>, uri: String) -> Result<()> {
+        instructions::update_token_metadata::handler(ctx, uri)
+    }
+
+    /// Permanently disable metadata updates for the token (creator only)
+    pub fn lock_metadata(ctx: Context<LockMetadata>) -> Result<()> {
+        instructions::lock_metadata::handler(ctx)
+    }
+
+    /// Put an expired, ungraduated curve into sell-only wind-down
+    /// 
+    /// Permissionless, callable once `expires_at` has passed
+    pub fn expire_curve(ctx: Context<ExpireCurve>) -> Result<()> {
+        instructions::expire_curve::handler(ctx)
+    }
+
+    /// Close an emptied curve after the wind-down grace period (creator only)
+    /// 
+    /// Burns the remaining supply; unredeemed SOL goes to the treasury, rent to the creator
+    pub fn close_expired_curve(ctx: Context<CloseExpiredCurve>) -> Result<()> {
+        instructions::close_expired_curve::handler(ctx)
+    }
+
+    /// Cancel a launch before anyone but the creator has traded (creator only)
+    /// 
+    /// Refunds the initial buy and rent; the creation fee is not refunded
+    pub fn cancel_launch(ctx: Context<CancelLaunch>) -> Result<()> {
+        instructions::cancel_launch::handler(ctx)
+    }
+
+    /// Close a migrated curve and its vaults, returning the rent to the creator
+    /// 
+    /// Permissionless, once the locked dev buy and all auction tokens are claimed
+    pub fn close_graduated_curve(ctx: Context<CloseGraduatedCurve>) -> Result<()> {
+        instructions::close_graduated_curve::handler(ctx)
+    }
+
+    /// Claim trading fees from the locked Meteora LP after graduation
+    /// 
+    /// Callable by the creator or the protocol, splits by creator_share_bps
+    pub fn claim_pool_fees(ctx: Context<ClaimPoolFees>) -> Result<()> {
+        instructions::claim_pool_fees::handler(ctx)
+    }
+
+    /// Configure referral tiers by referral count and referred volume
+    /// 
+    /// Upgrade authority only
+    pub fn set_referral_tiers(ctx: Context<SetReferralTiers>, tiers: Vec<ReferralTier>) -> Result<()> {
+        instructions::set_referral_tiers::handler(ctx, tiers)
+    }
+
+    /// Withdraw referral fees accrued in the caller's referral vault
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::claim_referral_fees::handler(ctx)
+    }
+
+    /// Change username, releasing the old referral code
+    pub fn change_username(ctx: Context<ChangeUsername>, new_username: String) -> Result<()> {
+        instructions::change_username::handler(ctx, new_username)
+    }
+
+    /// Create an extra referral code tagged with a campaign ID
+    pub fn create_referral_code(ctx: Context<CreateReferralCode>, code: String, campaign_id: u16) -> Result<()> {
+        instructions::create_referral_code::handler(ctx, code, campaign_id)
+    }
+
+    /// Close the caller's profile, username code and referral vault
+    /// 
+    /// Campaign codes to close go in remaining_accounts
+    pub fn close_user_profile<'info>(ctx: Context<'_, '_, 'info, 'info, CloseUserProfile<'info>>) -> Result<()> {
+        instructions::close_user_profile::handler(ctx)
+    }
+
+    /// Fund a bonus for referrers of this token (creator only)
+    /// 
+    /// From the creator's SOL or from accumulated creator fees
+    pub fn fund_referral_boost(ctx: Context<FundReferralBoost>, amount: u64, bonus_bps: u16, from_creator_fees: bool) -> Result<()> {
+        instructions::fund_referral_boost::handler(ctx, amount, bonus_bps, from_creator_fees)
+    }
+
+    /// Configure how long referrer bindings last (0 = forever)
+    /// 
+    /// Upgrade authority only
+    pub fn set_referral_binding_duration(ctx: Context<SetReferralBindingDuration>, duration: i64) -> Result<()> {
+        instructions::set_referral_binding_duration::handler(ctx, duration)
+    }
+
+    /// Nominate a new owner for a campaign referral code (None cancels)
+    pub fn transfer_referral_code(ctx: Context<TransferReferralCode>, new_owner: Option<Pubkey>) -> Result<()> {
+        instructions::transfer_referral_code::handler(ctx, new_owner)
+    }
+
+    /// Accept a pending referral code transfer (referees' earnings follow the code)
+    pub fn accept_referral_code(ctx: Context<AcceptReferralCode>) -> Result<()> {
+        instructions::accept_referral_code::handler(ctx)
+    }
+
+    /// Reclaim an unspent referral boost once the curve stops trading (creator only)
+    pub fn close_referral_boost(ctx: Context<CloseReferralBoost>) -> Result<()> {
+        instructions::close_referral_boost::handler(ctx)
+    }
+
+    /// Create the referral vault for a profile registered before vaults existed
+    pub fn init_referral_vault(ctx: Context<InitReferralVault>) -> Result<()> {
+        instructions::init_referral_vault::handler(ctx)
+    }
+
+    /// Sell the creator's locked dev buy back to a winding-down curve
+    /// 
+    /// Curve-only Token-2022 launches pass the transfer hook accounts in remaining_accounts
+    pub fn refund_creator_lock<'info>(ctx: Context<'_, '_, '_, 'info, RefundCreatorLock<'info>>) -> Result<()> {
+        instructions::refund_creator_lock::handler(ctx)
+    }
+
+    /// Move a username code registered before normalization to its lowercase seed
+    /// 
+    /// Permissionless; renames the owner's profile to the normalized name
+    pub fn migrate_referral_code(ctx: Context<MigrateReferralCode>) -> Result<()> {
+        instructions::migrate_referral_code::handler(ctx)
+    }
+
     /// Register a new user profile with a unique username
     pub fn register_user(ctx: Context<RegisterUser>, username: String) -> Result<()> {
         instructions::register_user::handler(ctx, username)
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use crate::meteora_interface::mpl_token_metadata;
+
+// ============================================
+// INSTRUCTION DISCRIMINATORS
+// ============================================
+// Metaplex Token Metadata uses a single-byte (Shank) discriminator
+
+/// Discriminator for `update_metadata_accounts_v2`
+pub const UPDATE_METADATA_ACCOUNTS_V2_DISCRIMINATOR: u8 = 15;
+
+/// Discriminator for `create_metadata_accounts_v3`
+pub const CREATE_METADATA_ACCOUNTS_V3_DISCRIMINATOR: u8 = 33;
+
+// ============================================
+// DATA V2 STRUCT
+// ============================================
+/// Token metadata as stored by Metaplex
+/// Creators, collection and uses are always None, so their inner types are placeholders
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DataV2 {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    /// Royalties in basis points (unused for fungible tokens)
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<u8>>,
+    pub collection: Option<Vec<u8>>,
+    pub uses: Option<Vec<u8>>,
+}
+
+impl DataV2 {
+    pub fn new(name: String, symbol: String, uri: String) -> Self {
+        Self {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        }
+    }
+}
+
+// ============================================
+// CPI ACCOUNT STRUCTS
+// ============================================
+
+/// Accounts required for `create_metadata_accounts_v3`
+#[derive(Accounts)]
+pub struct CreateMetadataAccountsV3<'info> {
+    /// CHECK: Metadata PDA to create
+    #[account(mut)]
+    pub metadata: AccountInfo<'info>,
+    /// CHECK: Token mint
+    pub mint: AccountInfo<'info>,
+    /// CHECK: Mint authority (signer)
+    pub mint_authority: AccountInfo<'info>,
+    /// CHECK: Payer for rent (signer)
+    #[account(mut)]
+    pub payer: AccountInfo<'info>,
+    /// CHECK: Update authority (signer)
+    pub update_authority: AccountInfo<'info>,
+    /// CHECK: System program
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Accounts required for `update_metadata_accounts_v2`
+#[derive(Accounts)]
+pub struct UpdateMetadataAccountsV2<'info> {
+    /// CHECK: Metadata PDA to update
+    #[account(mut)]
+    pub metadata: AccountInfo<'info>,
+    /// CHECK: Current update authority (signer)
+    pub update_authority: AccountInfo<'info>,
+}
+
+// ============================================
+// CPI FUNCTIONS
+// ============================================
+
+/// Create the Metaplex metadata account for a fungible mint
+pub fn create_metadata_accounts_v3<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, CreateMetadataAccountsV3<'info>>,
+    data: DataV2,
+    is_mutable: bool,
+) -> Result<()> {
+    let mut ix_data = vec![CREATE_METADATA_ACCOUNTS_V3_DISCRIMINATOR];
+    ix_data.extend_from_slice(&data.try_to_vec()?);
+    ix_data.extend_from_slice(&is_mutable.try_to_vec()?);
+    ix_data.extend_from_slice(&None::<u8>.try_to_vec()?); // collection_details
+
+    let accounts = vec![
+        AccountMeta::new(*ctx.accounts.metadata.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.mint.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.mint_authority.key, true),
+        AccountMeta::new(*ctx.accounts.payer.key, true),
+        AccountMeta::new_readonly(*ctx.accounts.update_authority.key, true),
+        AccountMeta::new_readonly(*ctx.accounts.system_program.key, false),
+    ];
+
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: mpl_token_metadata::ID,
+        accounts,
+        data: ix_data,
+    };
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.metadata.clone(),
+            ctx.accounts.mint.clone(),
+            ctx.accounts.mint_authority.clone(),
+            ctx.accounts.payer.clone(),
+            ctx.accounts.update_authority.clone(),
+            ctx.accounts.system_program.clone(),
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+/// Update metadata fields; `None` leaves a field unchanged
+pub fn update_metadata_accounts_v2<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, UpdateMetadataAccountsV2<'info>>,
+    data: Option<DataV2>,
+    new_update_authority: Option<Pubkey>,
+    is_mutable: Option<bool>,
+) -> Result<()> {
+    let mut ix_data = vec![UPDATE_METADATA_ACCOUNTS_V2_DISCRIMINATOR];
+    ix_data.extend_from_slice(&data.try_to_vec()?);
+    ix_data.extend_from_slice(&new_update_authority.try_to_vec()?);
+    ix_data.extend_from_slice(&None::<bool>.try_to_vec()?); // primary_sale_happened
+    ix_data.extend_from_slice(&is_mutable.try_to_vec()?);
+
+    let accounts = vec![
+        AccountMeta::new(*ctx.accounts.metadata.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.update_authority.key, true),
+    ];
+
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: mpl_token_metadata::ID,
+        accounts,
+        data: ix_data,
+    };
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.metadata.clone(),
+            ctx.accounts.update_authority.clone(),
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -20,23 +20,31 @@ pub mod mpl_token_metadata {
     declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
 }
 
+// ============================================
+// PDA SEEDS
+// ============================================
+
+/// Lock escrow PDA: `[LOCK_ESCROW_SEED, pool, owner]` under Dynamic AMM
+pub const LOCK_ESCROW_SEED: &[u8] = b"lock_escrow";
+
 // ============================================
 // INSTRUCTION DISCRIMINATORS
 // ============================================
-// These are the 8-byte Anchor discriminators for Meteora Dynamic AMM instructions
+// These are the 8-byte Anchor discriminators for Meteora Dynamic AMM instructions:
+// the first 8 bytes of sha256("global:<instruction name>")
 
 /// Discriminator for `initialize_customizable_permissionless_constant_product_pool`
 /// Uses sighash of instruction name
-pub const INIT_POOL_DISCRIMINATOR: [u8; 8] = [0x2f, 0x48, 0x59, 0x40, 0xf8, 0x00, 0x7e, 0x3c];
+pub const INIT_POOL_DISCRIMINATOR: [u8; 8] = [0x91, 0x18, 0xac, 0xc2, 0xdb, 0x7d, 0x03, 0xbe];
 
 /// Discriminator for `create_lock_escrow`
-pub const CREATE_LOCK_ESCROW_DISCRIMINATOR: [u8; 8] = [0x34, 0x9c, 0x9a, 0x79, 0xe4, 0x27, 0x34, 0x3f];
+pub const CREATE_LOCK_ESCROW_DISCRIMINATOR: [u8; 8] = [0x36, 0x57, 0xa5, 0x13, 0x45, 0xe3, 0xda, 0xe0];
 
 /// Discriminator for `lock`
-pub const LOCK_DISCRIMINATOR: [u8; 8] = [0x65, 0xd2, 0x38, 0xf5, 0xb0, 0x3f, 0x7c, 0x5e];
+pub const LOCK_DISCRIMINATOR: [u8; 8] = [0x15, 0x13, 0xd0, 0x2b, 0xed, 0x3e, 0xff, 0x57];
 
 /// Discriminator for `claim_fee`
-pub const CLAIM_FEE_DISCRIMINATOR: [u8; 8] = [0xa9, 0x20, 0x4f, 0x8f, 0x5b, 0x3a, 0x7e, 0x9c];
+pub const CLAIM_FEE_DISCRIMINATOR: [u8; 8] = [0xa9, 0x20, 0x4f, 0x89, 0x88, 0xe8, 0x46, 0x89];
 
 // ============================================
 // CUSTOMIZABLE PARAMS STRUCT
@@ -200,7 +208,7 @@ pub struct Lock<'info> {
     pub b_vault_lp: AccountInfo<'info>,
 }
 
-/// Accounts required for `claim_fee` instruction
+/// Accounts required for `claim_fee` instruction (in Meteora's IDL order)
 #[derive(Accounts)]
 pub struct ClaimFee<'info> {
     /// CHECK: Pool account
@@ -212,13 +220,17 @@ pub struct ClaimFee<'info> {
     /// CHECK: Lock escrow account
     #[account(mut)]
     pub lock_escrow: AccountInfo<'info>,
-    /// Owner of the lock escrow
-    pub owner: Signer<'info>,
-    /// CHECK: Source tokens (escrow vault, for compatibility)
+    /// CHECK: Owner of the lock escrow (may be a PDA signing via seeds)
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+    /// CHECK: Owner's LP token account, the fee LP is withdrawn through it
+    #[account(mut)]
     pub source_tokens: AccountInfo<'info>,
-    /// CHECK: Escrow vault
+    /// CHECK: Escrow vault holding the locked LP
     #[account(mut)]
     pub escrow_vault: AccountInfo<'info>,
+    /// CHECK: Token program
+    pub token_program: AccountInfo<'info>,
     /// CHECK: Token vault A
     #[account(mut)]
     pub a_token_vault: AccountInfo<'info>,
@@ -249,8 +261,6 @@ pub struct ClaimFee<'info> {
     /// CHECK: User token B to receive fees
     #[account(mut)]
     pub user_b_token: AccountInfo<'info>,
-    /// CHECK: Token program
-    pub token_program: AccountInfo<'info>,
     /// CHECK: Vault program
     pub vault_program: AccountInfo<'info>,
 }
@@ -442,8 +452,12 @@ pub fn claim_fee<'info>(
         AccountMeta::new(*ctx.accounts.pool.key, false),
         AccountMeta::new(*ctx.accounts.lp_mint.key, false),
         AccountMeta::new(*ctx.accounts.lock_escrow.key, false),
-        AccountMeta::new_readonly(*ctx.accounts.owner.key, true),
-        AccountMeta::new_readonly(*ctx.accounts.source_tokens.key, false),
+        AccountMeta::new(*ctx.accounts.owner.key, true),
+        AccountMeta::new(*ctx.accounts.source_tokens.key, false),
+        AccountMeta::new(*ctx.accounts.escrow_vault.key, false),
+        AccountMeta::new_readonly(*ctx.accounts.token_program.key, false),
+        AccountMeta::new(*ctx.accounts.a_token_vault.key, false),
+        AccountMeta::new(*ctx.accounts.b_token_vault.key, false),
         AccountMeta::new(*ctx.accounts.a_vault.key, false),
         AccountMeta::new(*ctx.accounts.b_vault.key, false),
         AccountMeta::new(*ctx.accounts.a_vault_lp.key, false),
@@ -453,10 +467,6 @@ pub fn claim_fee<'info>(
         AccountMeta::new(*ctx.accounts.user_a_token.key, false),
         AccountMeta::new(*ctx.accounts.user_b_token.key, false),
         AccountMeta::new_readonly(*ctx.accounts.vault_program.key, false),
-        AccountMeta::new(*ctx.accounts.escrow_vault.key, false),
-        AccountMeta::new_readonly(*ctx.accounts.token_program.key, false),
-        AccountMeta::new(*ctx.accounts.a_token_vault.key, false),
-        AccountMeta::new(*ctx.accounts.b_token_vault.key, false),
     ];
 
     let ix = anchor_lang::solana_program::instruction::Instruction {
@@ -473,6 +483,10 @@ pub fn claim_fee<'info>(
             ctx.accounts.lock_escrow.clone(),
             ctx.accounts.owner.to_account_info(),
             ctx.accounts.source_tokens.clone(),
+            ctx.accounts.escrow_vault.clone(),
+            ctx.accounts.token_program.clone(),
+            ctx.accounts.a_token_vault.clone(),
+            ctx.accounts.b_token_vault.clone(),
             ctx.accounts.a_vault.clone(),
             ctx.accounts.b_vault.clone(),
             ctx.accounts.a_vault_lp.clone(),
@@ -482,13 +496,27 @@ pub fn claim_fee<'info>(
             ctx.accounts.user_a_token.clone(),
             ctx.accounts.user_b_token.clone(),
             ctx.accounts.vault_program.clone(),
-            ctx.accounts.escrow_vault.clone(),
-            ctx.accounts.token_program.clone(),
-            ctx.accounts.a_token_vault.clone(),
-            ctx.accounts.b_token_vault.clone(),
         ],
         ctx.signer_seeds,
     )?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+
+    fn sighash(name: &str) -> [u8; 8] {
+        Sha256::digest(format!("global:{}", name))[..8].try_into().unwrap()
+    }
+
+    #[test]
+    fn discriminators_match_instruction_sighashes() {
+        assert_eq!(INIT_POOL_DISCRIMINATOR, sighash("initialize_customizable_permissionless_constant_product_pool"));
+        assert_eq!(CREATE_LOCK_ESCROW_DISCRIMINATOR, sighash("create_lock_escrow"));
+        assert_eq!(LOCK_DISCRIMINATOR, sighash("lock"));
+        assert_eq!(CLAIM_FEE_DISCRIMINATOR, sighash("claim_fee"));
+    }
+}
@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer as SolTransfer};
+use crate::state::{ReferralBoost, ReferralCode, ReferralConfig, ReferralVault, UserProfile};
+use crate::constants::*;
+use crate::errors::FuseError;
+use crate::events::{ReferralBoostPaid, ReferralFeePaid, ReferrerBound};
+use crate::instructions::fund_referral_boost::pay_referral_boost;
+
+// ============================================
+// SHARED REFERRAL PAYOUT (buy + sell)
+// ============================================
+
+/// Where the referral share of the protocol fee is paid from
+pub enum FeeSource<'a, 'info> {
+    /// The trader pays from their wallet (buy)
+    Wallet {
+        payer: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+    },
+    /// The fee is already in the curve PDA (sell)
+    Curve(&'a AccountInfo<'info>),
+}
+
+impl<'a, 'info> FeeSource<'a, 'info> {
+    fn pay(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        match self {
+            FeeSource::Wallet { payer, system_program } => {
+                let cpi_context = CpiContext::new(
+                    (*system_program).clone(),
+                    SolTransfer {
+                        from: (*payer).clone(),
+                        to: to.clone(),
+                    },
+                );
+                transfer(cpi_context, amount)
+            }
+            FeeSource::Curve(curve) => {
+                **curve.try_borrow_mut_lamports()? -= amount;
+                **to.try_borrow_mut_lamports()? += amount;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The optional referral accounts shared by `Buy` and `Sell`
+pub struct ReferralAccounts<'a, 'info> {
+    pub user_profile: &'a mut Option<Account<'info, UserProfile>>,
+    pub referrer_profile: &'a mut Option<Account<'info, UserProfile>>,
+    pub referrer_vault: &'a Option<Account<'info, ReferralVault>>,
+    pub second_referrer_profile: &'a mut Option<Account<'info, UserProfile>>,
+    pub second_referrer_vault: &'a Option<Account<'info, ReferralVault>>,
+    pub second_referral_code_account: &'a Option<Account<'info, ReferralCode>>,
+    pub referral_config: &'a ReferralConfig,
+    pub referral_code_account: &'a mut Option<Account<'info, ReferralCode>>,
+    pub referral_boost: &'a mut Option<Account<'info, ReferralBoost>>,
+}
+
+/// Pays the trader's referrers their share of `protocol_fee`
+///
+/// Resolves the active referrer (following transferred codes), binds a
+/// new one when `allow_binding` is set, pays the tiered direct share, the
+/// creator's boost and the second-tier share, and credits the binding
+/// code. Returns what is left of the protocol fee for the treasury.
+#[allow(clippy::too_many_arguments)]
+pub fn distribute_referral_fee<'info>(
+    accounts: ReferralAccounts<'_, 'info>,
+    source: FeeSource<'_, 'info>,
+    mint: Pubkey,
+    user: Pubkey,
+    protocol_fee: u64,
+    volume: u64,
+    allow_binding: bool,
+    now: i64,
+) -> Result<u64> {
+    let mut final_protocol_fee = protocol_fee;
+    let config = accounts.referral_config;
+
+    // Determine the referrer key
+    let mut active_referrer = None;
+    let mut should_bind_referrer = false;
+
+    // 1. Check if user is already bound to a referrer (and it hasn't expired)
+    if let Some(user_profile) = accounts.user_profile.as_ref() {
+        active_referrer = user_profile.active_referrer(config, now);
+
+        // Referees of a transferred code follow it to its new owner, so
+        // the code they were bound through must always be passed
+        if let (Some(_), Some(referred_via)) = (active_referrer, user_profile.referred_via) {
+            let code = accounts.referral_code_account
+                .as_ref()
+                .ok_or(FuseError::ReferralCodeRequired)?;
+            require_keys_eq!(code.key(), referred_via, FuseError::ReferralCodeRequired);
+            active_referrer = Some(code.owner);
+        }
+    }
+
+    // 2. If not bound, check if a valid referrer is provided in accounts
+    if active_referrer.is_none() && allow_binding {
+        if let Some(referrer_profile) = accounts.referrer_profile.as_ref() {
+            require_keys_neq!(referrer_profile.authority, user, FuseError::CannotReferSelf);
+            active_referrer = Some(referrer_profile.authority);
+            should_bind_referrer = true;
+        }
+    }
+
+    // A code transferred to its own referee pays nobody
+    let Some(referrer_key) = active_referrer.filter(|referrer| *referrer != user) else {
+        return Ok(final_protocol_fee);
+    };
+
+    // We need both the profile and the vault to be present and match the key
+    let (Some(referrer_profile), Some(referrer_vault)) =
+        (accounts.referrer_profile.as_mut(), accounts.referrer_vault.as_ref())
+    else {
+        return Ok(final_protocol_fee);
+    };
+    if referrer_profile.authority != referrer_key || referrer_vault.authority != referrer_key {
+        return Ok(final_protocol_fee);
+    }
+
+    // =====================
+    // BIND NEW REFERRER
+    // =====================
+    if should_bind_referrer {
+        if let Some(user_profile) = accounts.user_profile.as_mut() {
+            // Rebinding after expiry doesn't count the user again
+            if user_profile.referrer != Some(referrer_key) {
+                referrer_profile.referral_count = referrer_profile.referral_count
+                    .checked_add(1)
+                    .ok_or(FuseError::MathOverflow)?;
+            }
+            let previous_code = user_profile.referred_via;
+
+            user_profile.referrer = Some(referrer_key);
+            user_profile.referrer_bound_at = now;
+            user_profile.referred_via = None;
+
+            // Record the code used, if it belongs to this referrer
+            if let Some(code) = accounts.referral_code_account.as_mut() {
+                if code.owner == referrer_key {
+                    user_profile.referred_via = Some(code.key());
+                    if previous_code != Some(code.key()) {
+                        code.referral_count = code.referral_count
+                            .checked_add(1)
+                            .ok_or(FuseError::MathOverflow)?;
+                    }
+                }
+            }
+
+            emit!(ReferrerBound {
+                user,
+                referrer: referrer_key,
+                referral_code: user_profile.referred_via,
+                timestamp: now,
+            });
+        }
+    }
+
+    // =====================
+    // DIRECT REFERRER (by tier)
+    // =====================
+    let fee_share = config.fee_share(referrer_profile);
+    let referral_fee = protocol_fee
+        .checked_mul(fee_share)
+        .ok_or(FuseError::MathOverflow)?
+        .checked_div(100)
+        .ok_or(FuseError::MathOverflow)?;
+    final_protocol_fee = final_protocol_fee.checked_sub(referral_fee).ok_or(FuseError::MathOverflow)?;
+
+    source.pay(&referrer_vault.to_account_info(), referral_fee)?;
+
+    referrer_profile.accrue_referral_fee(referral_fee).ok_or(FuseError::MathOverflow)?;
+    referrer_profile.referred_volume = referrer_profile.referred_volume.checked_add(volume).unwrap_or(referrer_profile.referred_volume);
+
+    emit!(ReferralFeePaid {
+        mint,
+        trader: user,
+        referrer: referrer_key,
+        amount: referral_fee,
+        tier: 1,
+        timestamp: now,
+    });
+    msg!("Ref fee accrued: {} to {}", referral_fee, referrer_key);
+
+    // Credit the code that bound this user
+    let referred_via = accounts.user_profile.as_ref().and_then(|profile| profile.referred_via);
+    if let Some(code) = accounts.referral_code_account.as_mut() {
+        if referred_via == Some(code.key()) {
+            code.total_fees = code.total_fees.checked_add(referral_fee).unwrap_or(code.total_fees);
+        }
+    }
+
+    // =====================
+    // CREATOR BOOST
+    // =====================
+    // Only for a referrer bound on the trader's profile, never an ad hoc one
+    let bound_referrer = accounts.user_profile.is_some();
+    if let Some(referral_boost) = accounts.referral_boost.as_mut().filter(|_| bound_referrer) {
+        let bonus = pay_referral_boost(referral_boost, referrer_vault, volume)?;
+        if bonus > 0 {
+            referrer_profile.accrue_referral_fee(bonus).ok_or(FuseError::MathOverflow)?;
+            emit!(ReferralBoostPaid {
+                mint,
+                trader: user,
+                referrer: referrer_key,
+                amount: bonus,
+                budget_remaining: referral_boost.budget_remaining,
+                timestamp: now,
+            });
+        }
+    }
+
+    // =====================
+    // SECOND TIER
+    // =====================
+    // Paid when the referrer's own binding is still active, following a
+    // transferred code to its new owner like the direct referrer
+    if let (Some(second_profile), Some(second_vault)) =
+        (accounts.second_referrer_profile.as_mut(), accounts.second_referrer_vault.as_ref())
+    {
+        let mut second_referrer = referrer_profile.active_referrer(config, now);
+        if let (Some(_), Some(referred_via)) = (second_referrer, referrer_profile.referred_via) {
+            let code = accounts.second_referral_code_account
+                .as_ref()
+                .ok_or(FuseError::ReferralCodeRequired)?;
+            require_keys_eq!(code.key(), referred_via, FuseError::ReferralCodeRequired);
+            second_referrer = Some(code.owner);
+        }
+
+        let second_key = second_profile.authority;
+        if second_referrer == Some(second_key)
+            && second_vault.authority == second_key
+            && second_key != user
+            && second_key != referrer_key
+        {
+            let second_fee = protocol_fee
+                .checked_mul(SECOND_TIER_REFERRAL_FEE_SHARE)
+                .ok_or(FuseError::MathOverflow)?
+                .checked_div(100)
+                .ok_or(FuseError::MathOverflow)?;
+            final_protocol_fee = final_protocol_fee.checked_sub(second_fee).ok_or(FuseError::MathOverflow)?;
+
+            source.pay(&second_vault.to_account_info(), second_fee)?;
+
+            second_profile.accrue_referral_fee(second_fee).ok_or(FuseError::MathOverflow)?;
+
+            emit!(ReferralFeePaid {
+                mint,
+                trader: user,
+                referrer: second_key,
+                amount: second_fee,
+                tier: 2,
+                timestamp: now,
+            });
+            msg!("Tier 2 ref fee accrued: {} to {}", second_fee, second_key);
+        }
+    }
+
+    Ok(final_protocol_fee)
+}
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
+use crate::constants::REFERRAL_FEE_SHARE;
 
 // 1. THE BONDING CURVE (The Math)
 #[account]
 #[derive(InitSpace)]
 pub struct BondingCurve {
-    pub creator: Pubkey,            // Who launched this?
+    pub creator: Pubkey,            // Who launched this? (fee and creator-lock rights)
     pub token_mint: Pubkey,         // The token address
     pub token_total_supply: u64,    // How many tokens exist?
     
@@ -33,6 +34,63 @@ pub struct BondingCurve {
     pub symbol: String,             // Token symbol/ticker
     #[max_len(200)]
     pub uri: String,                // Metadata URI (IPFS/Arweave)
+
+    // New fields are appended below so existing curves can be grown in
+    // place by `realloc_account`
+
+    // Opening Auction
+    pub opening_auction_end: i64,   // End of the commit window (0 = no auction)
+    pub opening_settled: bool,      // True once the auction cleared and trading opened
+
+    // Max Wallet
+    pub max_wallet_bps: u16,        // Max holding per token account in bps of supply (0 = no limit)
+
+    // Locked Dev Buy
+    pub creator_locked_tokens: u64,   // Initial buy held in the creator-lock vault
+    pub creator_released_tokens: u64, // Already released to the creator
+    pub creator_vesting_duration: i64, // Linear vesting after graduation (0 = all at once)
+    pub graduated_at: i64,            // Unix timestamp of migration (0 = still on curve)
+
+    // Curve-Only Mode
+    pub curve_only_transfers: bool, // fuse-hook only allows curve trades until graduation
+
+    // Creator Fee Split
+    pub has_fee_split: bool,        // Creator fees are shared via a CreatorFeeSplit PDA
+
+    // Creator Transfer
+    pub pending_creator: Option<Pubkey>, // Proposed new creator, must accept
+
+    // Token Program
+    pub token_program: Pubkey,      // SPL Token or Token-2022 (owner of the mint)
+
+    // Metadata Lock
+    pub metadata_locked: bool,      // Creator gave up metadata updates before graduation
+
+    // Expiry
+    pub expires_at: i64,            // Buys stop after this timestamp (0 = never expires)
+    pub wind_down_at: i64,          // When expire_curve ran (0 = not winding down)
+
+    // Launch Cancellation
+    pub traded: bool,               // Anyone besides the creator's initial buy has bought or bid
+
+    // Referral Boost
+    pub has_referral_boost: bool,   // A ReferralBoost account exists and must be closed with the curve
+}
+
+impl BondingCurve {
+    /// Largest balance a single token account may hold while on the curve
+    /// (None = no limit, always None after graduation)
+    pub fn max_wallet_amount(&self) -> Option<u64> {
+        if self.complete || self.max_wallet_bps == 0 {
+            return None;
+        }
+        Some(((self.token_total_supply as u128) * (self.max_wallet_bps as u128) / 10_000) as u64)
+    }
+
+    /// True once an expiring curve has passed `expires_at` (sell-only from then on)
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at > 0 && now >= self.expires_at
+    }
 }
 
 // 2. GLOBAL PROTOCOL STATE
@@ -59,6 +117,29 @@ pub struct UserProfile {
     pub referral_count: u64,        // How many users they referred
     pub total_referral_fees: u64,   // Earnings from referrals
     pub bump: u8,
+
+    // New fields are appended below (see `realloc_account`)
+    pub referred_volume: u64,       // SOL traded by referred users (drives the referral tier)
+    pub unclaimed_referral_fees: u64, // Sitting in the ReferralVault
+    pub claimed_referral_fees: u64, // Already withdrawn
+    pub referred_via: Option<Pubkey>, // ReferralCode that bound the referrer (None = bound by profile)
+    pub referrer_bound_at: i64,     // When the current referrer was bound
+}
+
+impl UserProfile {
+    /// Records a referral fee deposited into this user's ReferralVault
+    pub fn accrue_referral_fee(&mut self, amount: u64) -> Option<()> {
+        self.total_referral_fees = self.total_referral_fees.checked_add(amount)?;
+        self.unclaimed_referral_fees = self.unclaimed_referral_fees.checked_add(amount)?;
+        Some(())
+    }
+
+    /// The bound referrer, unless the binding has expired under `config`
+    pub fn active_referrer(&self, config: &ReferralConfig, now: i64) -> Option<Pubkey> {
+        let expired = config.binding_duration > 0
+            && now >= self.referrer_bound_at.saturating_add(config.binding_duration);
+        self.referrer.filter(|_| !expired)
+    }
 }
 
 // 4. REFERRAL CODE MAPPING
@@ -69,4 +150,355 @@ pub struct ReferralCode {
     #[max_len(20)]
     pub code: String,
     pub bump: u8,
+
+    // New fields are appended below (see `realloc_account`)
+    pub campaign_id: u16,           // 0 = the username code, others set by the owner per channel
+    pub referral_count: u64,        // Users bound through this code
+    pub total_fees: u64,            // Referral fees earned from those users
+    pub pending_owner: Option<Pubkey>, // Proposed new owner, must accept (campaign codes only)
+}
+
+// 5. OPENING AUCTION (Batch auction escrow, holds committed SOL)
+#[account]
+#[derive(InitSpace)]
+pub struct OpeningAuction {
+    pub mint: Pubkey,
+    pub total_committed: u64,       // Gross SOL committed by all bidders
+    pub tokens_allocated: u64,      // Tokens bought at the uniform clearing price
+    pub sol_refund: u64,            // Committed SOL the capped buy didn't spend
+    pub committed_claimed: u64,     // Bid amounts already claimed
+    pub tokens_claimed: u64,
+    pub sol_refunded: u64,
+    pub settled: bool,
+    pub bump: u8,
+}
+
+// 6. OPENING BID (One per bidder per auction)
+#[account]
+#[derive(InitSpace)]
+pub struct OpeningBid {
+    pub bidder: Pubkey,
+    pub amount: u64,                // SOL committed by this bidder
+    pub bump: u8,
+}
+
+// 7. CREATOR FEE SPLIT (Shares the creator fee between several wallets)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct FeeRecipient {
+    pub wallet: Pubkey,
+    pub share_bps: u16,             // Share of creator fees in basis points
+    pub claimed: u64,               // Lamports already paid out
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorFeeSplit {
+    pub mint: Pubkey,
+    #[max_len(5)]
+    pub recipients: Vec<FeeRecipient>,
+    pub total_received: u64,        // Creator fees swept in from the curve
+    pub bump: u8,
+}
+
+impl CreatorFeeSplit {
+    /// Lamports currently owed to the recipient at `index`
+    pub fn owed(&self, index: usize) -> Option<u64> {
+        let recipient = self.recipients.get(index)?;
+        let entitled = (self.total_received as u128)
+            .checked_mul(recipient.share_bps as u128)?
+            .checked_div(10_000)? as u64;
+        entitled.checked_sub(recipient.claimed)
+    }
+}
+
+// 8. POOL FEES (Owns the Meteora lock escrow after graduation)
+#[account]
+#[derive(InitSpace)]
+pub struct PoolFees {
+    pub mint: Pubkey,
+    pub pool: Pubkey,               // Meteora pool created at migration
+    pub lock_escrow: Pubkey,        // Lock escrow holding the LP, owned by this PDA
+    pub creator: Pubkey,            // Receives the creator share, follows creator transfers
+    pub protocol_authority: Pubkey, // Upgrade authority at migration, receives the protocol share
+    pub creator_share_bps: u16,     // Fixed at migration from POOL_FEE_CREATOR_SHARE_BPS
+    pub total_token_claimed: u64,   // Token A fees claimed so far
+    pub total_sol_claimed: u64,     // WSOL fees claimed so far
+    pub bump: u8,
+}
+
+impl PoolFees {
+    /// Splits a claimed amount into (creator, protocol) shares
+    pub fn split(&self, amount: u64) -> Option<(u64, u64)> {
+        let creator = (amount as u128)
+            .checked_mul(self.creator_share_bps as u128)?
+            .checked_div(10_000)? as u64;
+        Some((creator, amount.checked_sub(creator)?))
+    }
+}
+
+// 9. REFERRAL CONFIG (Tiered rates and binding expiry, set by the upgrade authority)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ReferralTier {
+    pub min_referrals: u64,         // Referred users needed for this tier
+    pub min_volume: u64,            // Referred SOL volume needed for this tier
+    pub fee_share: u64,             // Percent of the protocol fee paid to the referrer
+}
+
+#[account]
+#[derive(InitSpace, Default)]
+pub struct ReferralConfig {
+    #[max_len(5)]
+    pub tiers: Vec<ReferralTier>,   // Ascending; the highest tier reached applies
+    pub binding_duration: i64,      // Seconds a referrer stays bound (0 = forever)
+    pub bump: u8,
+}
+
+impl ReferralConfig {
+    /// Reads the config PDA, or the defaults (base share for everyone,
+    /// bindings never expire) until the upgrade authority creates it
+    pub fn load_or_default(info: &AccountInfo) -> Result<Self> {
+        if info.owner != &crate::ID {
+            return Ok(Self::default());
+        }
+        Self::try_deserialize(&mut &info.try_borrow_data()?[..])
+    }
+
+    /// Share of the protocol fee earned by `referrer`
+    /// (REFERRAL_FEE_SHARE until the first tier is reached)
+    pub fn fee_share(&self, referrer: &UserProfile) -> u64 {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| {
+                referrer.referral_count >= tier.min_referrals
+                    && referrer.referred_volume >= tier.min_volume
+            })
+            .map_or(REFERRAL_FEE_SHARE, |tier| tier.fee_share)
+    }
+}
+
+// 10. REFERRAL VAULT (Holds a referrer's accrued fees until claimed)
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralVault {
+    pub authority: Pubkey,
+    pub bump: u8,
+}
+
+// 11. REFERRAL BOOST (Creator-funded bonus for referrers of one token)
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralBoost {
+    pub mint: Pubkey,
+    pub bonus_bps: u16,             // Bonus in bps of referred trade volume
+    pub budget_remaining: u64,      // Lamports left to pay out (held by this account)
+    pub total_paid: u64,            // Lamports paid to referrers so far
+    pub bump: u8,
+}
+
+// 12. CLOSED PROFILE (Keeps a closed profile's referrer binding for re-registration)
+#[account]
+#[derive(InitSpace)]
+pub struct ClosedProfile {
+    pub referrer: Option<Pubkey>,
+    pub referrer_bound_at: i64,
+    pub referred_via: Option<Pubkey>,
+    pub bump: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(max_wallet_bps: u16, complete: bool) -> BondingCurve {
+        BondingCurve {
+            creator: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            token_total_supply: 1_000_000_000_000_000,
+            virtual_sol_reserves: 0,
+            virtual_token_reserves: 0,
+            real_sol_reserves: 0,
+            real_token_reserves: 0,
+            complete,
+            bump: 255,
+            creator_fee_accumulated: 0,
+            launch_timestamp: 0,
+            name: String::new(),
+            symbol: String::new(),
+            uri: String::new(),
+            pending_creator: None,
+            token_program: Pubkey::default(),
+            opening_auction_end: 0,
+            opening_settled: false,
+            creator_locked_tokens: 0,
+            creator_released_tokens: 0,
+            creator_vesting_duration: 0,
+            graduated_at: 0,
+            max_wallet_bps,
+            traded: false,
+            has_referral_boost: false,
+            expires_at: 0,
+            wind_down_at: 0,
+            curve_only_transfers: false,
+            has_fee_split: false,
+            metadata_locked: false,
+        }
+    }
+
+    fn profile(referral_count: u64, referred_volume: u64) -> UserProfile {
+        UserProfile {
+            authority: Pubkey::new_unique(),
+            username: "alice".to_string(),
+            referrer: None,
+            referral_count,
+            total_referral_fees: 0,
+            bump: 255,
+            referred_via: None,
+            referrer_bound_at: 0,
+            referred_volume,
+            unclaimed_referral_fees: 0,
+            claimed_referral_fees: 0,
+        }
+    }
+
+    fn config(tiers: Vec<(u64, u64, u64)>, binding_duration: i64) -> ReferralConfig {
+        ReferralConfig {
+            tiers: tiers
+                .into_iter()
+                .map(|(min_referrals, min_volume, fee_share)| ReferralTier { min_referrals, min_volume, fee_share })
+                .collect(),
+            binding_duration,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn max_wallet_amount_disabled_or_graduated() {
+        assert_eq!(curve(0, false).max_wallet_amount(), None);
+        assert_eq!(curve(100, true).max_wallet_amount(), None);
+    }
+
+    #[test]
+    fn max_wallet_amount_share_of_supply() {
+        assert_eq!(curve(1, false).max_wallet_amount(), Some(100_000_000_000));
+        assert_eq!(curve(10_000, false).max_wallet_amount(), Some(1_000_000_000_000_000));
+    }
+
+    #[test]
+    fn active_referrer_forever_without_duration() {
+        let mut user = profile(0, 0);
+        user.referrer = Some(Pubkey::new_unique());
+        assert_eq!(user.active_referrer(&config(vec![], 0), i64::MAX), user.referrer);
+    }
+
+    #[test]
+    fn active_referrer_expires_exactly_at_duration() {
+        let mut user = profile(0, 0);
+        user.referrer = Some(Pubkey::new_unique());
+        user.referrer_bound_at = 1_000;
+        let config = config(vec![], 500);
+
+        assert_eq!(user.active_referrer(&config, 1_499), user.referrer);
+        assert_eq!(user.active_referrer(&config, 1_500), None);
+    }
+
+    #[test]
+    fn active_referrer_none_when_unbound() {
+        assert_eq!(profile(0, 0).active_referrer(&config(vec![], 500), 0), None);
+    }
+
+    #[test]
+    fn active_referrer_saturates_far_future_bindings() {
+        let mut user = profile(0, 0);
+        user.referrer = Some(Pubkey::new_unique());
+        user.referrer_bound_at = i64::MAX - 1;
+        assert_eq!(user.active_referrer(&config(vec![], 500), i64::MAX - 1), user.referrer);
+    }
+
+    #[test]
+    fn accrue_referral_fee_tracks_total_and_unclaimed() {
+        let mut user = profile(0, 0);
+        user.accrue_referral_fee(40).unwrap();
+        user.accrue_referral_fee(2).unwrap();
+        assert_eq!(user.total_referral_fees, 42);
+        assert_eq!(user.unclaimed_referral_fees, 42);
+
+        user.unclaimed_referral_fees = u64::MAX;
+        assert_eq!(user.accrue_referral_fee(1), None);
+    }
+
+    #[test]
+    fn fee_share_base_rate_without_tiers() {
+        assert_eq!(config(vec![], 0).fee_share(&profile(1_000, u64::MAX)), REFERRAL_FEE_SHARE);
+    }
+
+    #[test]
+    fn fee_share_tier_thresholds_are_inclusive() {
+        let config = config(vec![(10, 1_000, 20), (50, 5_000, 30)], 0);
+
+        assert_eq!(config.fee_share(&profile(9, 1_000)), REFERRAL_FEE_SHARE);
+        assert_eq!(config.fee_share(&profile(10, 999)), REFERRAL_FEE_SHARE);
+        assert_eq!(config.fee_share(&profile(10, 1_000)), 20);
+        assert_eq!(config.fee_share(&profile(49, 1_000_000)), 20);
+        assert_eq!(config.fee_share(&profile(50, 5_000)), 30);
+    }
+
+    #[test]
+    fn referral_config_defaults_until_created() {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::default();
+        let (mut lamports, mut data) = (0, vec![]);
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        let config = ReferralConfig::load_or_default(&info).unwrap();
+
+        assert_eq!(config.fee_share(&profile(1_000, u64::MAX)), REFERRAL_FEE_SHARE);
+        assert_eq!(config.binding_duration, 0);
+    }
+
+    #[test]
+    fn fee_split_owed_rounds_down_per_recipient() {
+        let recipient = |share_bps, claimed| FeeRecipient { wallet: Pubkey::new_unique(), share_bps, claimed };
+        let split = CreatorFeeSplit {
+            mint: Pubkey::new_unique(),
+            recipients: vec![recipient(3_333, 0), recipient(3_333, 1), recipient(3_334, 0)],
+            total_received: 10,
+            bump: 255,
+        };
+
+        assert_eq!(split.owed(0), Some(3));
+        assert_eq!(split.owed(1), Some(2));
+        assert_eq!(split.owed(2), Some(3));
+        assert_eq!(split.owed(3), None);
+    }
+
+    #[test]
+    fn fee_split_owed_none_when_overclaimed() {
+        let split = CreatorFeeSplit {
+            mint: Pubkey::new_unique(),
+            recipients: vec![FeeRecipient { wallet: Pubkey::new_unique(), share_bps: 10_000, claimed: 11 }],
+            total_received: 10,
+            bump: 255,
+        };
+        assert_eq!(split.owed(0), None);
+    }
+
+    #[test]
+    fn pool_fees_split_rounding_goes_to_protocol() {
+        let pool_fees = PoolFees {
+            mint: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            lock_escrow: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            protocol_authority: Pubkey::new_unique(),
+            creator_share_bps: 5_000,
+            total_token_claimed: 0,
+            total_sol_claimed: 0,
+            bump: 255,
+        };
+
+        assert_eq!(pool_fees.split(0), Some((0, 0)));
+        assert_eq!(pool_fees.split(1), Some((0, 1)));
+        assert_eq!(pool_fees.split(101), Some((50, 51)));
+        assert_eq!(pool_fees.split(u64::MAX), Some((u64::MAX / 2, u64::MAX - u64::MAX / 2)));
+    }
 }
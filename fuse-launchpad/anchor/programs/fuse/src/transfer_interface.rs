@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain;
+
+// ============================================
+// CPI ACCOUNT STRUCTS
+// ============================================
+
+/// Accounts required for `transfer_checked`
+#[derive(Accounts)]
+pub struct TransferChecked<'info> {
+    /// CHECK: Source token account
+    #[account(mut)]
+    pub from: AccountInfo<'info>,
+    /// CHECK: Token mint
+    pub mint: AccountInfo<'info>,
+    /// CHECK: Destination token account
+    #[account(mut)]
+    pub to: AccountInfo<'info>,
+    /// CHECK: Owner or delegate of the source account (signer)
+    pub authority: AccountInfo<'info>,
+}
+
+// ============================================
+// CPI FUNCTIONS
+// ============================================
+
+/// `transfer_checked` under either SPL Token or Token-2022
+///
+/// If the mint has a transfer hook, the hook program, its extra account
+/// metas PDA and every extra account it lists must be passed as the
+/// context's remaining accounts; they are resolved into the CPI here.
+pub fn transfer_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    onchain::invoke_transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+        &ctx.remaining_accounts,
+        amount,
+        decimals,
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -80,10 +80,12 @@ export function ReferralProvider({ children }: { children: ReactNode }) {
             // Build register user transaction
             const registerTx = await sdk.buildRegisterUserTx(wallet.publicKey, username);
 
-            // If we have a referrer, also set them
+            // If we have a referrer, also set them (through their code, which is their username)
             if (referrerAddress) {
                 try {
-                    const setReferrerTx = await sdk.buildSetReferrerTx(wallet.publicKey, referrerAddress);
+                    const referrerProfile = await sdk.getUserProfile(new PublicKey(referrerAddress));
+                    if (!referrerProfile?.username) throw new Error("Referrer has no referral code");
+                    const setReferrerTx = await sdk.buildSetReferrerTx(wallet.publicKey, referrerProfile.username);
                     // Add instructions from setReferrer to register transaction
                     registerTx.add(...setReferrerTx.instructions);
                 } catch (e) {
@@ -3,19 +3,13 @@ import { useConnection, useWallet } from '@solana/wallet-adapter-react';
 import {
     Keypair,
     PublicKey,
-    SystemProgram,
     Transaction,
-    TransactionInstruction,
-    SYSVAR_RENT_PUBKEY,
     ComputeBudgetProgram
 } from '@solana/web3.js';
-import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import * as anchor from '@coral-xyz/anchor';
+import { FuseSDK } from '@/sdk/fuse-sdk';
 
-// Program ID from your existing configuration
-const PROGRAM_ID = new PublicKey('CT4bS24PZXLzxuGMiHPLs3tpWYu72aVJ83UMgSNUeKY2');
 const TREASURY_WALLET = new PublicKey('4j1591eHGUZvRQgAGKSW2sriMQkDinSDRnA7oXdCHyT1');
-const CURVE_SEED = 'curve';
-const VAULT_SEED = 'vault';
 
 // Priority fee in microlamports (higher = faster inclusion)
 const PRIORITY_FEE = 50000; // 0.00005 SOL per compute unit
@@ -102,78 +96,24 @@ export const useLaunchToken = () => {
 
         const mintKeypair = Keypair.generate();
 
-        const [curvePda] = PublicKey.findProgramAddressSync(
-            [Buffer.from(CURVE_SEED), mintKeypair.publicKey.toBuffer()],
-            PROGRAM_ID
+        // Read-only wallet: the SDK only builds the transaction, the adapter signs it
+        const sdkWallet = {
+            publicKey,
+            signTransaction: async () => { throw new Error('Read only') },
+            signAllTransactions: async () => { throw new Error('Read only') }
+        } as unknown as anchor.Wallet;
+        const sdk = new FuseSDK(connection, sdkWallet, TREASURY_WALLET.toBase58());
+
+        const initialBuyLamports = Math.floor(initialBuyAmount * 1e9);
+        const createTokenTx = await sdk.buildCreateTokenTx(
+            publicKey,
+            mintKeypair,
+            name,
+            symbol,
+            metadataUri,
+            initialBuyLamports
         );
 
-        const [vaultPda] = PublicKey.findProgramAddressSync(
-            [Buffer.from(VAULT_SEED), mintKeypair.publicKey.toBuffer()],
-            PROGRAM_ID
-        );
-
-        // Build Instruction Data
-        const discriminator = Buffer.from([84, 52, 204, 228, 24, 140, 234, 75]);
-
-        const nameBytes = new TextEncoder().encode(name);
-        const nameBuffer = Buffer.alloc(4 + nameBytes.length);
-        nameBuffer.writeUInt32LE(nameBytes.length, 0);
-        nameBuffer.set(nameBytes, 4);
-
-        const symbolBytes = new TextEncoder().encode(symbol);
-        const symbolBuffer = Buffer.alloc(4 + symbolBytes.length);
-        symbolBuffer.writeUInt32LE(symbolBytes.length, 0);
-        symbolBuffer.set(symbolBytes, 4);
-
-        const uriBytes = new TextEncoder().encode(metadataUri);
-        const uriBuffer = Buffer.alloc(4 + uriBytes.length);
-        uriBuffer.writeUInt32LE(uriBytes.length, 0);
-        uriBuffer.set(uriBytes, 4);
-
-        const initialBuyLamports = BigInt(Math.floor(initialBuyAmount * 1e9));
-        const initialBuyBuffer = Buffer.alloc(9);
-        if (initialBuyLamports > 0n) {
-            initialBuyBuffer.writeUInt8(1, 0);
-            initialBuyBuffer.writeBigUInt64LE(initialBuyLamports, 1);
-        } else {
-            initialBuyBuffer.writeUInt8(0, 0);
-        }
-
-        const data = Buffer.concat([
-            discriminator,
-            nameBuffer,
-            symbolBuffer,
-            uriBuffer,
-            initialBuyBuffer
-        ]);
-
-        const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey('ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL');
-        const [creatorAta] = PublicKey.findProgramAddressSync(
-            [
-                publicKey.toBuffer(),
-                TOKEN_PROGRAM_ID.toBuffer(),
-                mintKeypair.publicKey.toBuffer(),
-            ],
-            ASSOCIATED_TOKEN_PROGRAM_ID
-        );
-
-        const instruction = new TransactionInstruction({
-            keys: [
-                { pubkey: publicKey, isSigner: true, isWritable: true },
-                { pubkey: curvePda, isSigner: false, isWritable: true },
-                { pubkey: mintKeypair.publicKey, isSigner: true, isWritable: true },
-                { pubkey: vaultPda, isSigner: false, isWritable: true },
-                { pubkey: creatorAta, isSigner: false, isWritable: true },
-                { pubkey: TREASURY_WALLET, isSigner: false, isWritable: true },
-                { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
-                { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
-                { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
-                { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
-            ],
-            programId: PROGRAM_ID,
-            data
-        });
-
         // Create transaction with priority fee for faster inclusion
         const transaction = new Transaction();
 
@@ -185,7 +125,7 @@ export const useLaunchToken = () => {
         );
 
         // Add main instruction
-        transaction.add(instruction);
+        transaction.add(...createTokenTx.instructions);
 
         // Retry loop - try up to 3 times with fresh blockhash each time
         let lastError: Error | null = null;
@@ -20,7 +20,6 @@ import {
 } from '@solana/web3.js';
 import {
   TOKEN_PROGRAM_ID,
-  ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
   createAssociatedTokenAccountInstruction,
   getAccount,
@@ -36,8 +35,16 @@ export const CURVE_SEED = Buffer.from('curve');
 export const VAULT_SEED = Buffer.from('vault');
 export const USER_SEED = Buffer.from('user');
 export const REFERRAL_SEED = Buffer.from('referral');
-
-// IDL Definition
+export const REFERRAL_VAULT_SEED = Buffer.from('referral_vault');
+export const REFERRAL_CONFIG_SEED = Buffer.from('referral_config');
+export const REFERRAL_BOOST_SEED = Buffer.from('referral_boost');
+export const CLOSED_PROFILE_SEED = Buffer.from('closed_profile');
+export const CREATOR_LOCK_SEED = Buffer.from('creator_lock');
+export const FEE_SPLIT_SEED = Buffer.from('fee_split');
+export const METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
+
+// IDL Definition (legacy format for @coral-xyz/anchor 0.29, converted from
+// the program's generated IDL; only the instructions and accounts used here)
 export const IDL: anchor.Idl = {
   "version": "0.1.0",
   "name": "fuse_launchpad",
@@ -45,10 +52,12 @@ export const IDL: anchor.Idl = {
     {
       "name": "initialize",
       "accounts": [
-        { "name": "curve", "isMut": true, "isSigner": false },
-        { "name": "tokenMint", "isMut": false, "isSigner": false },
-        { "name": "admin", "isMut": true, "isSigner": true },
+        { "name": "creator", "isMut": true, "isSigner": true },
+        { "name": "curveConfig", "isMut": true, "isSigner": false },
+        { "name": "mint", "isMut": true, "isSigner": true },
+        { "name": "vault", "isMut": true, "isSigner": false },
         { "name": "systemProgram", "isMut": false, "isSigner": false },
+        { "name": "tokenProgram", "isMut": false, "isSigner": false },
         { "name": "rent", "isMut": false, "isSigner": false }
       ],
       "args": [
@@ -64,18 +73,21 @@ export const IDL: anchor.Idl = {
         { "name": "curve", "isMut": true, "isSigner": false },
         { "name": "mint", "isMut": true, "isSigner": true },
         { "name": "vault", "isMut": true, "isSigner": false },
-        { "name": "creatorTokenAccount", "isMut": true, "isSigner": false },
+        { "name": "creatorLockVault", "isMut": true, "isSigner": false },
+        { "name": "metadata", "isMut": true, "isSigner": false },
+        { "name": "feeSplit", "isMut": true, "isSigner": false, "isOptional": true },
         { "name": "treasury", "isMut": true, "isSigner": false },
         { "name": "systemProgram", "isMut": false, "isSigner": false },
         { "name": "tokenProgram", "isMut": false, "isSigner": false },
-        { "name": "associatedTokenProgram", "isMut": false, "isSigner": false },
-        { "name": "rent", "isMut": false, "isSigner": false }
+        { "name": "rent", "isMut": false, "isSigner": false },
+        { "name": "metadataProgram", "isMut": false, "isSigner": false }
       ],
       "args": [
         { "name": "name", "type": "string" },
         { "name": "symbol", "type": "string" },
         { "name": "uri", "type": "string" },
-        { "name": "initialBuyLamports", "type": { "option": "u64" } }
+        { "name": "initialBuyLamports", "type": { "option": "u64" } },
+        { "name": "options", "type": { "defined": "LaunchOptions" } }
       ]
     },
     {
@@ -89,6 +101,13 @@ export const IDL: anchor.Idl = {
         { "name": "treasury", "isMut": true, "isSigner": false },
         { "name": "userProfile", "isMut": true, "isSigner": false, "isOptional": true },
         { "name": "referrerProfile", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "referrerVault", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "secondReferrerProfile", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "secondReferrerVault", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "referralConfig", "isMut": false, "isSigner": false },
+        { "name": "referralCodeAccount", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "referralBoost", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "secondReferralCodeAccount", "isMut": false, "isSigner": false, "isOptional": true },
         { "name": "tokenProgram", "isMut": false, "isSigner": false },
         { "name": "systemProgram", "isMut": false, "isSigner": false }
       ],
@@ -108,6 +127,13 @@ export const IDL: anchor.Idl = {
         { "name": "treasury", "isMut": true, "isSigner": false },
         { "name": "userProfile", "isMut": true, "isSigner": false, "isOptional": true },
         { "name": "referrerProfile", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "referrerVault", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "secondReferrerProfile", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "secondReferrerVault", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "referralConfig", "isMut": false, "isSigner": false },
+        { "name": "referralCodeAccount", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "referralBoost", "isMut": true, "isSigner": false, "isOptional": true },
+        { "name": "secondReferralCodeAccount", "isMut": false, "isSigner": false, "isOptional": true },
         { "name": "tokenProgram", "isMut": false, "isSigner": false },
         { "name": "systemProgram", "isMut": false, "isSigner": false }
       ],
@@ -121,7 +147,9 @@ export const IDL: anchor.Idl = {
       "accounts": [
         { "name": "user", "isMut": true, "isSigner": true },
         { "name": "userProfile", "isMut": true, "isSigner": false },
-        { "name": "referralCode", "isMut": true, "isSigner": false },
+        { "name": "referralCodeMapping", "isMut": true, "isSigner": false },
+        { "name": "referralVault", "isMut": true, "isSigner": false },
+        { "name": "closedProfile", "isMut": true, "isSigner": false },
         { "name": "systemProgram", "isMut": false, "isSigner": false }
       ],
       "args": [
@@ -133,8 +161,9 @@ export const IDL: anchor.Idl = {
       "accounts": [
         { "name": "user", "isMut": true, "isSigner": true },
         { "name": "userProfile", "isMut": true, "isSigner": false },
-        { "name": "referralCode", "isMut": false, "isSigner": false },
-        { "name": "systemProgram", "isMut": false, "isSigner": false }
+        { "name": "referralCodeAccount", "isMut": true, "isSigner": false },
+        { "name": "referrerProfile", "isMut": true, "isSigner": false },
+        { "name": "referralConfig", "isMut": false, "isSigner": false }
       ],
       "args": [
         { "name": "referralCode", "type": "string" }
@@ -143,7 +172,7 @@ export const IDL: anchor.Idl = {
   ],
   "accounts": [
     {
-      "name": "CurveState",
+      "name": "BondingCurve",
       "type": {
         "kind": "struct",
         "fields": [
@@ -160,7 +189,23 @@ export const IDL: anchor.Idl = {
           { "name": "launchTimestamp", "type": "i64" },
           { "name": "name", "type": "string" },
           { "name": "symbol", "type": "string" },
-          { "name": "uri", "type": "string" }
+          { "name": "uri", "type": "string" },
+          { "name": "openingAuctionEnd", "type": "i64" },
+          { "name": "openingSettled", "type": "bool" },
+          { "name": "maxWalletBps", "type": "u16" },
+          { "name": "creatorLockedTokens", "type": "u64" },
+          { "name": "creatorReleasedTokens", "type": "u64" },
+          { "name": "creatorVestingDuration", "type": "i64" },
+          { "name": "graduatedAt", "type": "i64" },
+          { "name": "curveOnlyTransfers", "type": "bool" },
+          { "name": "hasFeeSplit", "type": "bool" },
+          { "name": "pendingCreator", "type": { "option": "publicKey" } },
+          { "name": "tokenProgram", "type": "publicKey" },
+          { "name": "metadataLocked", "type": "bool" },
+          { "name": "expiresAt", "type": "i64" },
+          { "name": "windDownAt", "type": "i64" },
+          { "name": "traded", "type": "bool" },
+          { "name": "hasReferralBoost", "type": "bool" }
         ]
       }
     },
@@ -174,7 +219,12 @@ export const IDL: anchor.Idl = {
           { "name": "referrer", "type": { "option": "publicKey" } },
           { "name": "referralCount", "type": "u64" },
           { "name": "totalReferralFees", "type": "u64" },
-          { "name": "bump", "type": "u8" }
+          { "name": "bump", "type": "u8" },
+          { "name": "referredVolume", "type": "u64" },
+          { "name": "unclaimedReferralFees", "type": "u64" },
+          { "name": "claimedReferralFees", "type": "u64" },
+          { "name": "referredVia", "type": { "option": "publicKey" } },
+          { "name": "referrerBoundAt", "type": "i64" }
         ]
       }
     },
@@ -183,9 +233,39 @@ export const IDL: anchor.Idl = {
       "type": {
         "kind": "struct",
         "fields": [
-          { "name": "authority", "type": "publicKey" },
+          { "name": "owner", "type": "publicKey" },
           { "name": "code", "type": "string" },
-          { "name": "bump", "type": "u8" }
+          { "name": "bump", "type": "u8" },
+          { "name": "campaignId", "type": "u16" },
+          { "name": "referralCount", "type": "u64" },
+          { "name": "totalFees", "type": "u64" },
+          { "name": "pendingOwner", "type": { "option": "publicKey" } }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "LaunchOptions",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          { "name": "openingAuctionDuration", "type": { "option": "i64" } },
+          { "name": "maxWalletBps", "type": { "option": "u16" } },
+          { "name": "devVestingDuration", "type": { "option": "i64" } },
+          { "name": "curveOnlyTransfers", "type": "bool" },
+          { "name": "feeRecipients", "type": { "vec": { "defined": "FeeShare" } } },
+          { "name": "expiryDuration", "type": { "option": "i64" } }
+        ]
+      }
+    },
+    {
+      "name": "FeeShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          { "name": "wallet", "type": "publicKey" },
+          { "name": "shareBps", "type": "u16" }
         ]
       }
     }
@@ -237,6 +317,25 @@ export interface TokenInfo {
   creator: PublicKey;
 }
 
+// Optional launch settings for `create_token` (all off by default)
+export interface LaunchOptions {
+  openingAuctionDuration: number | null; // Seconds
+  maxWalletBps: number | null;
+  devVestingDuration: number | null; // Seconds
+  curveOnlyTransfers: boolean;
+  feeRecipients: { wallet: PublicKey; shareBps: number }[];
+  expiryDuration: number | null; // Seconds
+}
+
+export const DEFAULT_LAUNCH_OPTIONS: LaunchOptions = {
+  openingAuctionDuration: null,
+  maxWalletBps: null,
+  devVestingDuration: null,
+  curveOnlyTransfers: false,
+  feeRecipients: [],
+  expiryDuration: null,
+};
+
 export interface TradeQuote {
   tokensOut: bigint;
   solOut: bigint;
@@ -304,15 +403,85 @@ export class FuseSDK {
   }
 
   /**
-   * Derive the referral code PDA for a given code
+   * Derive the referral code PDA for a given code (codes are case-insensitive)
    */
   static getReferralCodePDA(code: string): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
-      [REFERRAL_SEED, Buffer.from(code)],
+      [REFERRAL_SEED, Buffer.from(code.toLowerCase())],
       PROGRAM_ID
     );
   }
 
+  /**
+   * Derive the referral vault PDA (holds a referrer's unclaimed fees)
+   */
+  static getReferralVaultPDA(user: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [REFERRAL_VAULT_SEED, user.toBuffer()],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Derive the marker a closed profile leaves behind (keeps its referrer binding)
+   */
+  static getClosedProfilePDA(user: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [CLOSED_PROFILE_SEED, user.toBuffer()],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Derive the global referral config PDA
+   */
+  static getReferralConfigPDA(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [REFERRAL_CONFIG_SEED],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Derive the creator's referral boost PDA for a given mint
+   */
+  static getReferralBoostPDA(mint: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [REFERRAL_BOOST_SEED, mint.toBuffer()],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Derive the creator-lock vault PDA (holds the initial buy until graduation)
+   */
+  static getCreatorLockPDA(mint: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [CREATOR_LOCK_SEED, mint.toBuffer()],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Derive the creator fee split PDA for a given mint
+   */
+  static getFeeSplitPDA(mint: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [FEE_SPLIT_SEED, mint.toBuffer()],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Derive the Metaplex metadata PDA for a given mint
+   */
+  static getMetadataPDA(mint: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('metadata'), METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      METADATA_PROGRAM_ID
+    );
+  }
+
   // =====================
   // BONDING CURVE MATH
   // =====================
@@ -618,25 +787,44 @@ export class FuseSDK {
     name: string,
     symbol: string,
     uri: string,
-    initialBuyLamports: number = 0
+    initialBuyLamports: number = 0,
+    options: Partial<LaunchOptions> = {}
   ): Promise<Transaction> {
     const [curvePda] = FuseSDK.getCurvePDA(mint.publicKey);
     const [vaultPda] = FuseSDK.getVaultPDA(mint.publicKey);
-    const creatorAta = await getAssociatedTokenAddress(mint.publicKey, creator);
+    const [creatorLockPda] = FuseSDK.getCreatorLockPDA(mint.publicKey);
+    const [metadataPda] = FuseSDK.getMetadataPDA(mint.publicKey);
+    const launchOptions = { ...DEFAULT_LAUNCH_OPTIONS, ...options };
 
+    // The initial buy is locked in the creator-lock vault until graduation
     const tx = await this.program.methods
-      .createToken(name, symbol, uri, new BN(initialBuyLamports))
+      .createToken(
+        name,
+        symbol,
+        uri,
+        initialBuyLamports > 0 ? new BN(initialBuyLamports) : null,
+        {
+          openingAuctionDuration: launchOptions.openingAuctionDuration !== null ? new BN(launchOptions.openingAuctionDuration) : null,
+          maxWalletBps: launchOptions.maxWalletBps,
+          devVestingDuration: launchOptions.devVestingDuration !== null ? new BN(launchOptions.devVestingDuration) : null,
+          curveOnlyTransfers: launchOptions.curveOnlyTransfers,
+          feeRecipients: launchOptions.feeRecipients,
+          expiryDuration: launchOptions.expiryDuration !== null ? new BN(launchOptions.expiryDuration) : null,
+        }
+      )
       .accounts({
         creator: creator,
         curve: curvePda,
         mint: mint.publicKey,
         vault: vaultPda,
-        creatorTokenAccount: creatorAta,
+        creatorLockVault: creatorLockPda,
+        metadata: metadataPda,
+        feeSplit: launchOptions.feeRecipients.length > 0 ? FuseSDK.getFeeSplitPDA(mint.publicKey)[0] : null,
         treasury: this.treasury,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        metadataProgram: METADATA_PROGRAM_ID,
       })
       .transaction();
 
@@ -652,13 +840,17 @@ export class FuseSDK {
   ): Promise<Transaction> {
     const [userProfilePda] = FuseSDK.getUserProfilePDA(user);
     const [referralCodePda] = FuseSDK.getReferralCodePDA(username);
+    const [referralVaultPda] = FuseSDK.getReferralVaultPDA(user);
+    const [closedProfilePda] = FuseSDK.getClosedProfilePDA(user);
 
     const tx = await this.program.methods
       .registerUser(username)
       .accounts({
         user: user,
         userProfile: userProfilePda,
-        referralCode: referralCodePda,
+        referralCodeMapping: referralCodePda,
+        referralVault: referralVaultPda,
+        closedProfile: closedProfilePda,
         systemProgram: SystemProgram.programId,
       })
       .transaction();
@@ -675,14 +867,20 @@ export class FuseSDK {
   ): Promise<Transaction> {
     const [userProfilePda] = FuseSDK.getUserProfilePDA(user);
     const [referralCodePda] = FuseSDK.getReferralCodePDA(referralCode);
+    const [referralConfigPda] = FuseSDK.getReferralConfigPDA();
+
+    const referrer = await this.getReferrerFromCode(referralCode);
+    if (!referrer) throw new Error(`Unknown referral code: ${referralCode}`);
+    const [referrerProfilePda] = FuseSDK.getUserProfilePDA(referrer);
 
     const tx = await this.program.methods
       .setReferrer(referralCode)
       .accounts({
         user: user,
         userProfile: userProfilePda,
-        referralCode: referralCodePda,
-        systemProgram: SystemProgram.programId,
+        referralCodeAccount: referralCodePda,
+        referrerProfile: referrerProfilePda,
+        referralConfig: referralConfigPda,
       })
       .transaction();
 
@@ -704,6 +902,83 @@ export class FuseSDK {
     }
   }
 
+  /**
+   * Resolve the optional referral accounts for a buy or sell
+   *
+   * Follows the user's bound referrer (through the code they were bound
+   * with, so transferred codes pay their new owner) and that referrer's
+   * own referrer for the second tier. Accounts that don't exist on-chain
+   * are left out rather than failing the trade.
+   */
+  async getReferralAccounts(user: PublicKey, mint: PublicKey): Promise<Record<string, PublicKey | null>> {
+    const [userProfilePda] = FuseSDK.getUserProfilePDA(user);
+    const [referralConfigPda] = FuseSDK.getReferralConfigPDA();
+    const [referralBoostPda] = FuseSDK.getReferralBoostPDA(mint);
+
+    const accounts: Record<string, PublicKey | null> = {
+      userProfile: null,
+      referrerProfile: null,
+      referrerVault: null,
+      secondReferrerProfile: null,
+      secondReferrerVault: null,
+      referralConfig: referralConfigPda,
+      referralCodeAccount: null,
+      referralBoost: null,
+      secondReferralCodeAccount: null,
+    };
+
+    const exists = async (address: PublicKey) => (await this.connection.getAccountInfo(address)) !== null;
+
+    try {
+      // @ts-ignore - dynamic anchor program
+      const userProfileAccount = await this.program.account.userProfile.fetchNullable(userProfilePda);
+      if (!userProfileAccount) return accounts;
+      accounts.userProfile = userProfilePda;
+
+      let referrerWallet = userProfileAccount.referrer as PublicKey | null;
+      const referredVia = userProfileAccount.referredVia as PublicKey | null;
+      if (referrerWallet && referredVia) {
+        // @ts-ignore - dynamic anchor program
+        const codeAccount = await this.program.account.referralCode.fetch(referredVia);
+        accounts.referralCodeAccount = referredVia;
+        referrerWallet = codeAccount.owner as PublicKey;
+      }
+      if (!referrerWallet) return accounts;
+
+      const [referrerProfile] = FuseSDK.getUserProfilePDA(referrerWallet);
+      const [referrerVault] = FuseSDK.getReferralVaultPDA(referrerWallet);
+      if (!(await exists(referrerVault))) return accounts;
+      accounts.referrerProfile = referrerProfile;
+      accounts.referrerVault = referrerVault;
+
+      if (await exists(referralBoostPda)) {
+        accounts.referralBoost = referralBoostPda;
+      }
+
+      // @ts-ignore - dynamic anchor program
+      const referrerProfileAccount = await this.program.account.userProfile.fetchNullable(referrerProfile);
+      let secondWallet = referrerProfileAccount?.referrer as PublicKey | null;
+      const secondReferredVia = referrerProfileAccount?.referredVia as PublicKey | null;
+      if (secondWallet && secondReferredVia) {
+        // @ts-ignore - dynamic anchor program
+        const secondCodeAccount = await this.program.account.referralCode.fetch(secondReferredVia);
+        accounts.secondReferralCodeAccount = secondReferredVia;
+        secondWallet = secondCodeAccount.owner as PublicKey;
+      }
+      if (secondWallet) {
+        const [secondReferrerVault] = FuseSDK.getReferralVaultPDA(secondWallet);
+        if (await exists(secondReferrerVault)) {
+          accounts.secondReferrerProfile = FuseSDK.getUserProfilePDA(secondWallet)[0];
+          accounts.secondReferrerVault = secondReferrerVault;
+        }
+      }
+    } catch (e) {
+      // Trade without (or with partial) referral accounts
+    }
+
+    return accounts;
+  }
+
   /**
    * Build a buy transaction
    */
@@ -716,22 +991,7 @@ export class FuseSDK {
     const [curvePda] = FuseSDK.getCurvePDA(mint);
     const [vaultPda] = FuseSDK.getVaultPDA(mint);
     const userAta = await getAssociatedTokenAddress(mint, user);
-    const [userProfilePda] = FuseSDK.getUserProfilePDA(user);
-
-    let referrerProfile = null;
-    let referrerWallet = null;
-
-    try {
-      // @ts-ignore - dynamic anchor program
-      const userProfileAccount = await this.program.account.userProfile.fetchNullable(userProfilePda);
-      if (userProfileAccount && userProfileAccount.referrer) {
-        referrerWallet = userProfileAccount.referrer as PublicKey;
-        const [refProfilePda] = FuseSDK.getUserProfilePDA(referrerWallet);
-        referrerProfile = refProfilePda;
-      }
-    } catch (e) {
-      // Ignore error if profile doesn't exist
-    }
+    const referralAccounts = await this.getReferralAccounts(user, mint);
 
     const tx = await this.program.methods
       .buy(new BN(solAmount.toString()), new BN(minTokensOut.toString()))
@@ -742,8 +1002,7 @@ export class FuseSDK {
         vault: vaultPda,
         userTokenAccount: userAta,
         treasury: this.treasury,
-        userProfile: userProfilePda,
-        referrerProfile: referrerProfile,
+        ...referralAccounts,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
@@ -795,22 +1054,7 @@ export class FuseSDK {
     const [curvePda] = FuseSDK.getCurvePDA(mint);
     const [vaultPda] = FuseSDK.getVaultPDA(mint);
     const userAta = await getAssociatedTokenAddress(mint, user);
-    const [userProfilePda] = FuseSDK.getUserProfilePDA(user);
-
-    let referrerProfile = null;
-    let referrerWallet = null;
-
-    try {
-      // @ts-ignore - dynamic anchor program
-      const userProfileAccount = await this.program.account.userProfile.fetchNullable(userProfilePda);
-      if (userProfileAccount && userProfileAccount.referrer) {
-        referrerWallet = userProfileAccount.referrer as PublicKey;
-        const [refProfilePda] = FuseSDK.getUserProfilePDA(referrerWallet);
-        referrerProfile = refProfilePda;
-      }
-    } catch (e) {
-      // Ignore error if profile doesn't exist
-    }
+    const referralAccounts = await this.getReferralAccounts(user, mint);
 
     const tx = await this.program.methods
       .sell(new BN(tokenAmount.toString()), new BN(minSolOut.toString()))
@@ -821,8 +1065,7 @@ export class FuseSDK {
         vault: vaultPda,
         userTokenAccount: userAta,
         treasury: this.treasury,
-        userProfile: userProfilePda,
-        referrerProfile: referrerProfile,
+        ...referralAccounts,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })